@@ -1,18 +1,30 @@
+use chrono::{Datelike, NaiveDate, Weekday};
 use icondata::AiAlignRightOutlined;
 use leptos::*;
 use leptos_icons::Icon;
-use leptos_inputs::{AddressInputStyle, InputAddress};
+use leptos_inputs::InputEmailList;
+use leptos_inputs::{max_len, min_len, regex, AnyValidator, Gap, InputPassword, Validator};
+use leptos_inputs::{not_breached, EmbeddedPasswordList};
+use leptos_inputs::{required_unless, InputPasswordConfirm, ValidationMode};
+use leptos_inputs::{Address, AddressInputStyle, InputAddress, InputAddressStructured};
+use leptos_inputs::{AddressFormat, AddressSuggestion, InMemoryAddressProvider};
+use leptos_inputs::{country_name, Autocomplete, MatchMode, Matcher, SuggestionSource};
 use leptos_inputs::{
     Button, ButtonAnimation, ButtonColor, ButtonRoundness, ButtonSize, ButtonStyle,
     PasswordInputStyle,
 };
 use leptos_inputs::{Direction, DisplayStrategy, Line};
-use leptos_inputs::{EmailInputStyle, InputEmail, InputText, TextInputStyle};
-use leptos_inputs::{FormBox, GlobalThemeProvider, ThemeToggler};
-use leptos_inputs::{Gap, GlobalTheme, InputPassword};
-use leptos_inputs::{Padding, PasswordValidationLevel, Popup};
+use leptos_inputs::{EmailInputStyle, EmailPolicy, InputEmail, InputText, TextInputStyle};
+use leptos_inputs::{InputDate, InputNumber};
+use leptos_inputs::{FieldErrors, FormBox, FormContext, GlobalThemeProvider, ThemeToggler};
+use leptos_inputs::{I18n, I18nProvider};
+use leptos_inputs::{
+    Padding, PasswordAutocomplete, PasswordPolicy, PasswordValidationLevel, Popup,
+};
 use leptos_meta::provide_meta_context;
 
+use crate::components::{username_available, Signup, SignupForm};
+
 #[component]
 pub fn App() -> impl IntoView {
     provide_meta_context();
@@ -24,7 +36,29 @@ pub fn App() -> impl IntoView {
     let custom_validation = move |val: &str| !val.is_empty();
     let custom_message = String::from("A password cannot be empty");
 
-    let title = Some("Input Demo".to_string());
+    let form = FormContext::new();
+    let new_password = create_rw_signal(String::new());
+    let confirmation = create_rw_signal(String::new());
+    let contact_email = create_rw_signal(String::new());
+    let phone = create_rw_signal(String::new());
+    let invitees = create_rw_signal(Vec::<String>::new());
+    let shipping_address = create_rw_signal(Address::new("US"));
+    let signup_form = FormContext::new();
+    let i18n = I18n::new("en");
+
+    let password_policy = PasswordPolicy::new()
+        .min_length(10)
+        .lowercase(1)
+        .uppercase(1)
+        .numbers(2)
+        .max_repeated(2)
+        .forbid(Signal::from(contact_email))
+        .deny(["Password123!", "Qwerty123!"]);
+
+    let username: AnyValidator = min_len(3)
+        .and(max_len(20))
+        .and(regex("^[a-z0-9_]*$").with_message("Only lowercase letters, digits and underscores."))
+        .into();
 
     let suggestions = Some(vec![
         "Password".to_string(),
@@ -36,21 +70,68 @@ pub fn App() -> impl IntoView {
         "Password7".to_string(),
     ]);
 
+    let saved_addresses = InMemoryAddressProvider::new([
+        Address {
+            street_lines: vec!["1600 Amphitheatre Pkwy".to_string()],
+            city: "Mountain View".to_string(),
+            region: "CA".to_string(),
+            postal_code: "94043".to_string(),
+            country: "US".to_string(),
+        },
+        Address {
+            street_lines: vec!["10 Rue de Rivoli".to_string()],
+            city: "Paris".to_string(),
+            postal_code: "75001".to_string(),
+            country: "FR".to_string(),
+            ..Default::default()
+        },
+    ]);
+    let address_search = create_rw_signal(String::new());
+    let country = create_rw_signal(None::<&'static str>);
+    let countries = SuggestionSource::list(AddressFormat::countries().collect::<Vec<_>>());
+    let product = create_rw_signal(None::<(&'static str, &'static str)>);
+    let products = SuggestionSource::list(vec![
+        ("Fruits", "Apple"),
+        ("Vegetables", "Artichoke"),
+        ("Fruits", "Banana"),
+        ("Vegetables", "Broccoli"),
+        ("Fruits", "Clementine"),
+        ("Vegetables", "Cucumber"),
+        ("Fruits", "Pineapple"),
+        ("Vegetables", "Pumpkin"),
+    ])
+    .matcher(Matcher::new(MatchMode::Fuzzy));
+    let quantity = create_rw_signal(Some(1u32));
+    let price = create_rw_signal(None::<f64>);
+    let birthday = create_rw_signal(None::<NaiveDate>);
+    let delivery = create_rw_signal(None::<NaiveDate>);
+    let today = chrono::Local::now().date_naive();
+    let weekend = |day: NaiveDate| matches!(day.weekday(), Weekday::Sat | Weekday::Sun);
+    let sku = create_rw_signal(None::<String>);
+    let skus = SuggestionSource::list((1..=10_000).map(|n| format!("SKU-{n:05}")).collect::<Vec<_>>())
+        .matcher(Matcher::new(MatchMode::Prefix));
+
     view! {
+        <I18nProvider i18n=i18n>
         <GlobalThemeProvider>
 
             <nav>
                 <div>
                     <h1>"Input Demo"</h1>
                     <ThemeToggler />
+                    <Line gap=Gap::Small>
+                        <Button style=ButtonStyle::Text size=ButtonSize::Small text="EN" on:click=move |_| i18n.set_locale("en")/>
+                        <Button style=ButtonStyle::Text size=ButtonSize::Small text="FR" on:click=move |_| i18n.set_locale("fr")/>
+                        <Button style=ButtonStyle::Text size=ButtonSize::Small text="DE" on:click=move |_| i18n.set_locale("de")/>
+                    </Line>
                 </div>
             </nav>
 
             <Popup title="Dialog demo" visible=true>
-                <FormBox padding=Padding::None>
-                    <form style="display: flex; flex-direction: column;gap: 1.5rem;">
-                        <InputText label=label_1.into() value=value required=true style=TextInputStyle::Outline/>
-                        <InputText label=label_2.into() value=value required=true style=TextInputStyle::Underline/>
+                <FormBox padding=Padding::None context=form validation_mode=ValidationMode::EagerAfterTouched>
+                    <form style="display: flex; flex-direction: column;gap: 1.5rem;" on:submit=|e| e.prevent_default()>
+                        <InputText label=label_1.into() value=value required=true style=TextInputStyle::Outline validator=username.clone()/>
+                        <InputText label=label_2.into() value=value required=true style=TextInputStyle::Underline validator=username async_validator=username_available/>
                         <InputText label=label_3.into() value=value required=true style=TextInputStyle::Rounded/>
 
                         <InputEmail email=value style=EmailInputStyle::Outline/>
@@ -69,11 +150,77 @@ pub fn App() -> impl IntoView {
                         <InputPassword password=value validate_change=PasswordValidationLevel::Custom(custom_validation, custom_message.clone()) style=PasswordInputStyle::Outline/>
                         <InputPassword password=value required=true validate_change=PasswordValidationLevel::Custom(custom_validation, custom_message) style=PasswordInputStyle::Underline/>
 
-                        <InputAddress address=value style=AddressInputStyle::Rounded suggestions=suggestions.into()/>
+                        <InputPassword password=new_password policy=password_policy async_validator=not_breached(EmbeddedPasswordList::default()) autocomplete=PasswordAutocomplete::NewPassword allow_paste=false generate=true checklist=true strength_meter=true/>
+                        <InputPasswordConfirm password=new_password confirmation=confirmation/>
+
+                        <InputEmail email=contact_email required=required_unless(phone) label="Contact email".to_string().into() policy=EmailPolicy::new().trim(true).lowercase(true).reject_disposable(true)/>
+                        <InputText label="Phone".to_string().into() value=phone required=required_unless(contact_email)/>
+
+                        <InputEmailList emails=invitees label="Invite teammates".to_string().into() policy=EmailPolicy::new().trim(true).lowercase(true)/>
+
+                        <InputAddress
+                            address=address_search
+                            label="Search a saved address"
+                            provider=saved_addresses
+                            on_select=move |suggestion: AddressSuggestion| shipping_address.set(suggestion.address)
+                        />
+                        <InputAddressStructured address=shipping_address style=AddressInputStyle::Outline/>
+
+                        <Autocomplete
+                            value=country
+                            source=countries
+                            display=|code: &'static str| country_name(code)
+                            key=|code: &'static str| code.to_string()
+                            parse=|text: String| AddressFormat::countries().find(|code| code.eq_ignore_ascii_case(text.trim()))
+                            render=|code: &'static str| view! { <span>{country_name(code)}" "<small>{code}</small></span> }.into_view()
+                            label="Country of residence"
+                            style=TextInputStyle::Outline
+                        />
+
+                        <Autocomplete
+                            value=product
+                            source=products
+                            display=|(_, name): (&'static str, &'static str)| name.to_string()
+                            group=|(category, _): (&'static str, &'static str)| category.to_string()
+                            label="Product"
+                        />
+
+                        <InputNumber value=quantity label="Quantity".to_string().into() min=1 max=99 required=true style=TextInputStyle::Outline/>
+                        <InputNumber value=price label="Price".to_string().into() min=0.0 step=0.05 precision=2 wheel=true/>
+                        <InputDate value=birthday label="Birthday" max=today style=TextInputStyle::Outline/>
+                        <InputDate
+                            value=delivery
+                            label="Delivery"
+                            min=today
+                            disabled_dates=weekend
+                            first_day=Weekday::Sun
+                            format="%d/%m/%Y"
+                            required=true
+                        />
+
+                        <Autocomplete value=sku source=skus display=|sku: String| sku label="SKU" visible_suggestions=8/>
+
+                        <InputAddress address=value style=AddressInputStyle::Rounded suggestions=suggestions.into() matcher=Matcher::new(MatchMode::Fuzzy)/>
+
+                        <Line justify=DisplayStrategy::SpaceBetween>
+                            <Button style=ButtonStyle::Text text="Reset" on:click=move |_| form.reset()/>
+                            <Button style=ButtonStyle::Solid disabled=Signal::derive(move || !form.is_valid().get()) on:click=move |_| { form.submit(); }/>
+                        </Line>
                 </form>
             </FormBox>
             </Popup>
 
+            <SignupForm
+                context=signup_form
+                on_submit=move |signup: Signup| {
+                    if signup.email == "taken@example.com" {
+                        signup_form.set_errors(FieldErrors::new().with("email", "This email is already registered."));
+                    } else {
+                        logging::log!("{signup:?}");
+                    }
+                }
+            />
+
             <Line direction=Direction::Column justify=DisplayStrategy::Center gap=Gap::Medium>
                 <Line justify=DisplayStrategy::SpaceBetween>
                     <Button
@@ -110,5 +257,6 @@ pub fn App() -> impl IntoView {
                 </Line>
             </Line>
         </GlobalThemeProvider>
+        </I18nProvider>
     }
 }
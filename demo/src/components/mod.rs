@@ -0,0 +1,5 @@
+mod signup;
+mod username;
+
+pub use signup::{Signup, SignupForm};
+pub use username::username_available;
@@ -0,0 +1,17 @@
+use leptos_inputs::validator::Validate;
+use leptos_inputs::Form;
+
+#[derive(Debug, Clone, Validate, Form)]
+#[validate(crate = "leptos_inputs::validator")]
+#[form(title = "Sign up", submit = "Create account")]
+pub struct Signup {
+    #[validate(length(min = 3, max = 20))]
+    pub username: String,
+    #[validate(email)]
+    pub email: String,
+    #[validate(length(min = 8))]
+    pub password: String,
+    #[validate(length(max = 50))]
+    #[form(label = "Company (optional)")]
+    pub company: Option<String>,
+}
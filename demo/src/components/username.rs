@@ -0,0 +1,13 @@
+use gloo_timers::future::TimeoutFuture;
+use leptos_inputs::ValidationError;
+
+/// An in-memory stand-in for a "is this username taken?" endpoint.
+pub async fn username_available(username: String) -> Result<(), ValidationError> {
+    const TAKEN: [&str; 3] = ["admin", "root", "soapy"];
+
+    TimeoutFuture::new(500).await;
+    if TAKEN.contains(&username.as_str()) {
+        return Err(ValidationError::new("This username is already taken."));
+    }
+    Ok(())
+}
@@ -0,0 +1,438 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::meta::ParseNestedMeta;
+use syn::{parenthesized, Data, DeriveInput, Expr, Fields, Ident, LitStr, Token, Type};
+
+#[derive(PartialEq)]
+enum InputKind {
+    Text,
+    Email,
+    Password,
+    Address,
+}
+
+struct FormField {
+    ident: Ident,
+    label: LitStr,
+    kind: InputKind,
+    optional: bool,
+    min_len: Option<Expr>,
+    max_len: Option<Expr>,
+}
+
+#[derive(Default)]
+struct FormOptions {
+    title: Option<LitStr>,
+    submit: Option<LitStr>,
+}
+
+pub(crate) fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "`Form` can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`Form` can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut options = FormOptions::default();
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("form")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("title") {
+                options.title = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("submit") {
+                options.submit = Some(meta.value()?.parse()?);
+            } else {
+                return Err(meta.error("expected `title` or `submit`"));
+            }
+            Ok(())
+        })?;
+    }
+
+    let fields = fields
+        .iter()
+        .map(parse_field)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let vis = &input.vis;
+    let ident = &input.ident;
+    let component = format_ident!("{}Form", ident);
+
+    let signals = fields.iter().map(|f| {
+        let signal = signal_ident(&f.ident);
+        quote! {
+            let #signal = ::leptos::create_rw_signal(::std::string::String::new());
+        }
+    });
+
+    let values = fields.iter().map(|f| {
+        let field = &f.ident;
+        let signal = signal_ident(&f.ident);
+        if f.optional {
+            quote! {
+                #field: {
+                    let value = ::leptos::SignalGetUntracked::get_untracked(&#signal);
+                    if value.is_empty() { None } else { Some(value) }
+                }
+            }
+        } else {
+            quote! {
+                #field: ::leptos::SignalGetUntracked::get_untracked(&#signal)
+            }
+        }
+    });
+
+    let inputs = fields.iter().map(render_input);
+
+    let title = match &options.title {
+        Some(title) => quote! { Some(::std::string::String::from(#title)) },
+        None => quote! { None },
+    };
+    let submit = options
+        .submit
+        .map(|submit| quote! { .text(::std::string::String::from(#submit)) });
+
+    let doc = format!("A form generated from [`{}`].", ident);
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[::leptos::component]
+        #vis fn #component(
+            /// Called with the value of the form once it is submitted and valid
+            #[prop(into)]
+            on_submit: ::leptos::Callback<#ident>,
+            /// The state of the form, used to display the errors returned by a server
+            #[prop(optional)]
+            context: Option<::leptos_inputs::FormContext>,
+        ) -> impl ::leptos::IntoView {
+            let form = context.unwrap_or_default();
+            #(#signals)*
+
+            let on_submit_form = move |ev: ::leptos::ev::SubmitEvent| {
+                ev.prevent_default();
+                if !form.submit() {
+                    return;
+                }
+                let value = #ident {
+                    #(#values,)*
+                };
+                match ::leptos_inputs::validator::Validate::validate(&value) {
+                    Ok(()) => ::leptos::Callable::call(&on_submit, value),
+                    Err(errors) => form.set_errors(errors.into()),
+                }
+            };
+
+            let children: ::leptos::Children = ::std::boxed::Box::new(move || {
+                let submit = ::leptos::component_view(
+                    &::leptos_inputs::Button,
+                    ::leptos::component_props_builder(&::leptos_inputs::Button)
+                        #submit
+                        .build(),
+                );
+                let form = ::leptos::html::form()
+                    .attr("class", "form-fields")
+                    .on(::leptos::ev::submit, on_submit_form)
+                    .child(::std::vec![#(#inputs,)* submit]);
+                ::leptos::Fragment::new(::std::vec![::leptos::IntoView::into_view(form)])
+            });
+
+            ::leptos::component_view(
+                &::leptos_inputs::FormBox,
+                ::leptos::component_props_builder(&::leptos_inputs::FormBox)
+                    .title(::leptos::MaybeSignal::from(#title))
+                    .context(form)
+                    .children(children)
+                    .build(),
+            )
+        }
+    })
+}
+
+fn parse_field(field: &syn::Field) -> syn::Result<FormField> {
+    let ident = field.ident.clone().expect("named field");
+    let optional = match string_type(&field.ty) {
+        Some(optional) => optional,
+        None => {
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                "`Form` fields must be of type `String` or `Option<String>`",
+            ))
+        }
+    };
+
+    let mut label = None;
+    let mut kind = None;
+    let mut email = false;
+    let mut min_len = None;
+    let mut max_len = None;
+
+    for attr in &field.attrs {
+        if attr.path().is_ident("form") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("label") {
+                    label = Some(meta.value()?.parse::<LitStr>()?);
+                } else if meta.path.is_ident("input") {
+                    let input = meta.value()?.parse::<LitStr>()?;
+                    kind = Some(match input.value().as_str() {
+                        "text" => InputKind::Text,
+                        "email" => InputKind::Email,
+                        "password" => InputKind::Password,
+                        "address" => InputKind::Address,
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                input,
+                                "expected `text`, `email`, `password` or `address`",
+                            ))
+                        }
+                    });
+                } else {
+                    return Err(meta.error("expected `label` or `input`"));
+                }
+                Ok(())
+            })?;
+        } else if attr.path().is_ident("validate") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("email") {
+                    email = true;
+                    skip(&meta)
+                } else if meta.path.is_ident("length") {
+                    meta.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("min") {
+                            min_len = Some(meta.value()?.parse()?);
+                        } else if meta.path.is_ident("max") {
+                            max_len = Some(meta.value()?.parse()?);
+                        } else if meta.path.is_ident("equal") {
+                            let equal: Expr = meta.value()?.parse()?;
+                            min_len = Some(equal.clone());
+                            max_len = Some(equal);
+                        } else {
+                            skip(&meta)?;
+                        }
+                        Ok(())
+                    })
+                } else {
+                    skip(&meta)
+                }
+            })?;
+        }
+    }
+
+    let kind = kind.unwrap_or(if email {
+        InputKind::Email
+    } else if ident == "password" {
+        InputKind::Password
+    } else {
+        InputKind::Text
+    });
+    let label = label.unwrap_or_else(|| LitStr::new(&humanize(&ident), ident.span()));
+
+    Ok(FormField {
+        ident,
+        label,
+        kind,
+        optional,
+        min_len,
+        max_len,
+    })
+}
+
+/// Consumes the arguments of a `validator` rule that is only checked on submit.
+fn skip(meta: &ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(Token![=]) {
+        meta.value()?.parse::<Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        let content;
+        parenthesized!(content in meta.input);
+        content.parse::<TokenStream>()?;
+    }
+    Ok(())
+}
+
+/// Returns whether the type is optional, or `None` if it is not a string.
+fn string_type(ty: &Type) -> Option<bool> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident == "String" {
+        return Some(false);
+    }
+    if segment.ident != "Option" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        syn::GenericArgument::Type(inner) if string_type(inner) == Some(false) => Some(true),
+        _ => None,
+    }
+}
+
+fn humanize(ident: &Ident) -> String {
+    let name = ident.to_string();
+    let name = name.trim_start_matches("r#").replace('_', " ");
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => name,
+    }
+}
+
+fn signal_ident(ident: &Ident) -> Ident {
+    format_ident!("{}_value", ident)
+}
+
+fn render_input(field: &FormField) -> TokenStream {
+    let signal = signal_ident(&field.ident);
+    let name = field.ident.to_string();
+    let label = &field.label;
+    let required = !field.optional;
+
+    let mut rules = Vec::new();
+    if let Some(min) = &field.min_len {
+        rules.push(quote! { ::leptos_inputs::min_len((#min) as usize) });
+    }
+    if let Some(max) = &field.max_len {
+        rules.push(quote! { ::leptos_inputs::max_len((#max) as usize) });
+    }
+    let validator = rules.into_iter().reduce(|a, b| {
+        quote! { ::leptos_inputs::Validator::and(#a, #b) }
+    });
+    let validator = validator.map(|v| quote! { .validator(#v) });
+    let label = quote! { ::leptos::MaybeSignal::from(::std::string::String::from(#label)) };
+
+    let (component, props) = match field.kind {
+        InputKind::Text => (
+            quote! { ::leptos_inputs::InputText },
+            quote! { .value(#signal).label(#label) },
+        ),
+        InputKind::Email => (
+            quote! { ::leptos_inputs::InputEmail },
+            quote! { .email(#signal).label(#label) },
+        ),
+        InputKind::Password => (
+            quote! { ::leptos_inputs::InputPassword },
+            quote! {
+                .password(#signal)
+                .label(#label)
+                .validate_change(::leptos_inputs::PasswordValidationLevel::Custom(
+                    |_| true,
+                    ::std::string::String::new(),
+                ))
+            },
+        ),
+        InputKind::Address => (
+            quote! { ::leptos_inputs::InputAddress },
+            quote! { .address(#signal).label(#label) },
+        ),
+    };
+
+    quote! {
+        ::leptos::component_view(
+            &#component,
+            ::leptos::component_props_builder(&#component)
+                #props
+                .name(#name)
+                .required(#required)
+                #validator
+                .build(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    fn expanded(input: DeriveInput) -> String {
+        expand(input).unwrap().to_string()
+    }
+
+    fn error(input: DeriveInput) -> String {
+        expand(input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn generates_a_form_component() {
+        let code = expanded(parse_quote! {
+            #[derive(Validate, Form)]
+            #[form(title = "Sign up", submit = "Create")]
+            pub struct Signup {
+                #[validate(length(min = 3, max = 20))]
+                username: String,
+                #[validate(email)]
+                email: String,
+                #[validate(length(min = 8))]
+                password: String,
+                #[form(label = "Home", input = "address")]
+                address: Option<String>,
+            }
+        });
+        assert!(code.contains("pub fn SignupForm"));
+        assert!(code.contains(":: leptos_inputs :: validator :: Validate :: validate (& value)"));
+        assert_eq!(
+            code.matches(":: validator ::").count(),
+            code.matches(":: leptos_inputs :: validator ::").count()
+        );
+        assert!(code.contains("\"Sign up\""));
+        assert!(code.contains(". text (:: std :: string :: String :: from (\"Create\"))"));
+        assert!(code.contains(":: leptos_inputs :: InputText"));
+        assert!(code.contains(":: leptos_inputs :: InputEmail"));
+        assert!(code.contains(":: leptos_inputs :: InputPassword"));
+        assert!(code.contains(":: leptos_inputs :: InputAddress"));
+        assert!(code.contains(
+            ":: leptos_inputs :: Validator :: and (:: leptos_inputs :: min_len ((3) as usize) , \
+             :: leptos_inputs :: max_len ((20) as usize))"
+        ));
+        assert!(code.contains(". name (\"address\") . required (false)"));
+        assert!(code.contains(". name (\"username\") . required (true)"));
+    }
+
+    #[test]
+    fn labels_default_to_the_field_names() {
+        let code = expanded(parse_quote! {
+            struct Contact {
+                first_name: String,
+                #[form(label = "E-mail")]
+                r#email: String,
+            }
+        });
+        assert!(code.contains("\"First name\""));
+        assert!(code.contains("\"E-mail\""));
+    }
+
+    #[test]
+    fn rejects_unsupported_items() {
+        let message = error(parse_quote! { enum Signup { A } });
+        assert_eq!(message, "`Form` can only be derived for structs");
+        let message = error(parse_quote! { struct Signup(String); });
+        assert_eq!(
+            message,
+            "`Form` can only be derived for structs with named fields"
+        );
+        let message = error(parse_quote! { struct Signup { age: u8 } });
+        assert_eq!(
+            message,
+            "`Form` fields must be of type `String` or `Option<String>`"
+        );
+        let message = error(parse_quote! {
+            struct Signup {
+                #[form(input = "date")]
+                day: String,
+            }
+        });
+        assert_eq!(message, "expected `text`, `email`, `password` or `address`");
+    }
+}
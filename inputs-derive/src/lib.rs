@@ -0,0 +1,36 @@
+mod form;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Derives a form component from a struct annotated with `validator` attributes.
+///
+/// For a struct `Signup`, a `SignupForm` component is generated. It renders an input
+/// for every field inside a `FormBox`, and calls `on_submit` with the struct once
+/// every input is valid and `Validate::validate` succeeds. Otherwise the errors of
+/// `Validate::validate` are displayed on the inputs, named after the fields.
+///
+/// The struct must derive `Validate`, and its fields must be `String` or `Option<String>`.
+/// Optional fields are not required. The `validator` crate is re-exported by
+/// `leptos_inputs`, so it does not need to be a dependency when the struct names it with
+/// `#[validate(crate = "leptos_inputs::validator")]`.
+///
+/// The input of a field is selected from its attributes:
+/// - `#[validate(email)]` renders an `InputEmail`
+/// - a field named `password` renders an `InputPassword`
+/// - any other field renders an `InputText`
+///
+/// `#[validate(length(min = .., max = ..))]` is checked while typing, the other
+/// `validator` rules are checked on submit.
+///
+/// The generated form is configured with the `form` attribute:
+/// - on the struct: `title = ".."` and `submit = ".."`, the text of the submit button,
+///   localized by default
+/// - on a field: `label = ".."`, `input = "text" | "email" | "password" | "address"`
+#[proc_macro_derive(Form, attributes(form))]
+pub fn derive_form(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    form::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
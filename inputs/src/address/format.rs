@@ -0,0 +1,427 @@
+use super::Address;
+use crate::validation::no_params;
+use crate::{translate, ValidationError};
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use AddressField::*;
+
+/// A part of an [`Address`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AddressField {
+    StreetLines,
+    City,
+    Region,
+    PostalCode,
+    Country,
+}
+
+/// The layout, the labels and the postal codes of the addresses of a country.
+///
+/// The bundled formats follow the postal conventions of each country, so the postal code
+/// comes before the city in France and after the state in the United States. Countries
+/// without a bundled format get a generic layout and accept any postal code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AddressFormat {
+    country: &'static str,
+    rows: &'static [&'static [AddressField]],
+    city_label: &'static str,
+    region_label: &'static str,
+    postal_label: &'static str,
+    region_required: bool,
+    postal_required: bool,
+    postal_pattern: Option<&'static str>,
+    postal_example: &'static str,
+}
+
+const POSTAL_CITY: &[&[AddressField]] = &[&[StreetLines], &[PostalCode, City]];
+const POSTAL_CITY_REGION: &[&[AddressField]] = &[&[StreetLines], &[PostalCode, City, Region]];
+const CITY_REGION_POSTAL: &[&[AddressField]] = &[&[StreetLines], &[City, Region, PostalCode]];
+
+const GENERIC: AddressFormat = AddressFormat {
+    country: "",
+    rows: &[&[StreetLines], &[City, Region], &[PostalCode]],
+    city_label: "address.city",
+    region_label: "address.region",
+    postal_label: "address.postal_code",
+    region_required: false,
+    postal_required: false,
+    postal_pattern: None,
+    postal_example: "",
+};
+
+const FORMATS: &[AddressFormat] = &[
+    AddressFormat::new("AT", r"\d{4}", "1010"),
+    AddressFormat::new("AU", r"\d{4}", "2000")
+        .layout(CITY_REGION_POSTAL)
+        .city("address.suburb")
+        .region("address.state")
+        .postal_code("address.postcode"),
+    AddressFormat::new("BE", r"\d{4}", "1000"),
+    AddressFormat::new("BR", r"\d{5}-?\d{3}", "01310-100")
+        .layout(&[&[StreetLines], &[City, Region], &[PostalCode]])
+        .region("address.state")
+        .postal_code("address.cep"),
+    AddressFormat::new(
+        "CA",
+        r"[ABCEGHJ-NPRSTVXY]\d[ABCEGHJ-NPRSTV-Z] ?\d[ABCEGHJ-NPRSTV-Z]\d",
+        "K1A 0B1",
+    )
+    .layout(CITY_REGION_POSTAL)
+    .region("address.province"),
+    AddressFormat::new("CH", r"\d{4}", "8001"),
+    AddressFormat::new("DE", r"\d{5}", "10115"),
+    AddressFormat::new("DK", r"\d{4}", "1050"),
+    AddressFormat::new("ES", r"\d{5}", "28013")
+        .layout(POSTAL_CITY_REGION)
+        .region("address.province"),
+    AddressFormat::new("FR", r"\d{2} ?\d{3}", "75001"),
+    AddressFormat::new(
+        "GB",
+        r"GIR ?0AA|[A-PR-UWYZ]([0-9]{1,2}|[A-HK-Y][0-9]{1,2}|[0-9][A-HJKS-UW]|[A-HK-Y][0-9][ABEHMNPRV-Y]) ?[0-9][ABD-HJLNP-UW-Z]{2}",
+        "SW1A 1AA",
+    )
+    .layout(&[&[StreetLines], &[City], &[PostalCode]])
+    .city("address.town")
+    .postal_code("address.postcode"),
+    AddressFormat::new(
+        "IE",
+        r"([AC-FHKNPRTV-Y][0-9]{2}|D6W) ?[0-9AC-FHKNPRTV-Y]{4}",
+        "D02 X285",
+    )
+    .layout(&[&[StreetLines], &[City], &[Region], &[PostalCode]])
+    .region("address.county")
+    .postal_code("address.eircode")
+    .optional_postal_code(),
+    AddressFormat::new("IN", r"\d{6}", "110001")
+        .layout(&[&[StreetLines], &[City, PostalCode], &[Region]])
+        .region("address.state")
+        .postal_code("address.pin"),
+    AddressFormat::new("IT", r"\d{5}", "00144")
+        .layout(POSTAL_CITY_REGION)
+        .region("address.province"),
+    AddressFormat::new("JP", r"\d{3}-?\d{4}", "100-0001")
+        .layout(&[&[PostalCode], &[Region], &[City], &[StreetLines]])
+        .region("address.prefecture"),
+    AddressFormat::new("LU", r"(L-)?\d{4}", "1111"),
+    AddressFormat::new("MX", r"\d{5}", "06000")
+        .layout(POSTAL_CITY_REGION)
+        .region("address.state"),
+    AddressFormat::new("NL", r"\d{4} ?[A-Z]{2}", "1012 AB"),
+    AddressFormat::new("NO", r"\d{4}", "0150"),
+    AddressFormat::new("NZ", r"\d{4}", "6011").layout(&[&[StreetLines], &[City, PostalCode]]),
+    AddressFormat::new("PT", r"\d{4}-\d{3}", "1100-148"),
+    AddressFormat::new("SE", r"\d{3} ?\d{2}", "111 22"),
+    AddressFormat::new("US", r"\d{5}(-\d{4})?", "94043")
+        .layout(CITY_REGION_POSTAL)
+        .region("address.state")
+        .postal_code("address.zip"),
+];
+
+/// The builders of the bundled formats.
+impl AddressFormat {
+    /// A format where the postal code comes before the city, without a region.
+    const fn new(country: &'static str, pattern: &'static str, example: &'static str) -> Self {
+        AddressFormat {
+            country,
+            rows: POSTAL_CITY,
+            postal_required: true,
+            postal_pattern: Some(pattern),
+            postal_example: example,
+            ..GENERIC
+        }
+    }
+
+    const fn layout(mut self, rows: &'static [&'static [AddressField]]) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    const fn city(mut self, label: &'static str) -> Self {
+        self.city_label = label;
+        self
+    }
+
+    /// Requires a region, with the label used by the country.
+    const fn region(mut self, label: &'static str) -> Self {
+        self.region_label = label;
+        self.region_required = true;
+        self
+    }
+
+    const fn postal_code(mut self, label: &'static str) -> Self {
+        self.postal_label = label;
+        self
+    }
+
+    const fn optional_postal_code(mut self) -> Self {
+        self.postal_required = false;
+        self
+    }
+}
+
+impl AddressFormat {
+    /// The format of the country, from its ISO 3166-1 alpha-2 code like `US`.
+    pub fn for_country(country: &str) -> Self {
+        FORMATS
+            .iter()
+            .find(|format| format.country.eq_ignore_ascii_case(country.trim()))
+            .copied()
+            .unwrap_or(GENERIC)
+    }
+
+    /// The codes of the countries with a bundled format.
+    pub fn countries() -> impl Iterator<Item = &'static str> {
+        FORMATS.iter().map(|format| format.country)
+    }
+
+    /// The code of the country, empty for the generic format.
+    pub fn country(&self) -> &'static str {
+        self.country
+    }
+
+    /// The fields in the order they are written, one slice per line.
+    pub fn rows(&self) -> &'static [&'static [AddressField]] {
+        self.rows
+    }
+
+    /// The label of the field in the current locale, like `ZIP code` or `Postcode`.
+    pub fn label(&self, field: AddressField) -> String {
+        let key = match field {
+            StreetLines => "address.street",
+            City => self.city_label,
+            Region => self.region_label,
+            PostalCode => self.postal_label,
+            Country => "address.country",
+        };
+        translate(key, &[])
+    }
+
+    /// Whether the field must be filled once any field of the address is.
+    pub fn is_required(&self, field: AddressField) -> bool {
+        match field {
+            StreetLines | City | Country => true,
+            Region => self.region_required,
+            PostalCode => self.postal_required,
+        }
+    }
+
+    /// An example of a valid postal code, empty for the generic format.
+    pub fn postal_example(&self) -> &'static str {
+        self.postal_example
+    }
+
+    /// Rejects the postal codes not following the pattern of the country, ignoring the case.
+    pub fn validate_postal_code(&self, code: &str) -> Result<(), ValidationError> {
+        let Some(pattern) = postal_patterns().get(self.country) else {
+            return Ok(());
+        };
+        if pattern.is_match(code.trim()) {
+            return Ok(());
+        }
+        Err(ValidationError::from_key(
+            "address.invalid_postal_code",
+            [("example", self.postal_example)],
+        ))
+    }
+
+    /// Validates a field of the address.
+    ///
+    /// Required fields may only be empty if the whole address is, and `required` is `false`.
+    pub fn validate_field(
+        &self,
+        address: &Address,
+        field: AddressField,
+        required: bool,
+    ) -> Result<(), ValidationError> {
+        let value = address.value(field);
+        if value.trim().is_empty() {
+            if self.is_required(field) && (required || !address.is_empty()) {
+                return Err(ValidationError::from_key("required", no_params()));
+            }
+            return Ok(());
+        }
+        match field {
+            PostalCode => self.validate_postal_code(&value),
+            _ => Ok(()),
+        }
+    }
+
+    /// Validates the fields of the address, returning the first error.
+    pub fn validate(&self, address: &Address, required: bool) -> Result<(), ValidationError> {
+        self.rows
+            .iter()
+            .flat_map(|row| row.iter())
+            .chain([&Country])
+            .try_for_each(|field| self.validate_field(address, *field, required))
+    }
+}
+
+/// The compiled postal code patterns of the bundled formats, anchored and case-insensitive.
+fn postal_patterns() -> &'static HashMap<&'static str, Regex> {
+    static PATTERNS: OnceLock<HashMap<&'static str, Regex>> = OnceLock::new();
+    PATTERNS.get_or_init(|| {
+        FORMATS
+            .iter()
+            .filter_map(|format| {
+                let pattern = format.postal_pattern?;
+                let regex =
+                    Regex::new(&format!("^(?i:{pattern})$")).expect("invalid postal code pattern");
+                Some((format.country, regex))
+            })
+            .collect()
+    })
+}
+
+/// The name of the country in the current locale, or its code if it is not known.
+pub fn country_name(country: &str) -> String {
+    let key = format!("country.{}", country.to_uppercase());
+    let name = translate(&key, &[]);
+    if name == key {
+        country.to_string()
+    } else {
+        name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address(
+        country: &str,
+        street: &str,
+        city: &str,
+        region: &str,
+        postal_code: &str,
+    ) -> Address {
+        Address {
+            street_lines: vec![street.to_string()],
+            city: city.to_string(),
+            region: region.to_string(),
+            postal_code: postal_code.to_string(),
+            country: country.to_string(),
+        }
+    }
+
+    #[test]
+    fn examples_follow_their_patterns() {
+        for country in AddressFormat::countries() {
+            let format = AddressFormat::for_country(country);
+            let example = format.postal_example();
+            assert!(!example.is_empty(), "{country}");
+            assert!(
+                format.validate_postal_code(example).is_ok(),
+                "{country} {example}"
+            );
+            let lowercase = example.to_lowercase();
+            assert!(
+                format.validate_postal_code(&lowercase).is_ok(),
+                "{country} {lowercase}"
+            );
+            assert!(format.validate_postal_code("!!").is_err(), "{country}");
+        }
+    }
+
+    #[test]
+    fn postal_codes() {
+        let cases = [
+            ("GB", "SW1A 1AA", true),
+            ("GB", "sw1a1aa", true),
+            ("GB", "M1 1AE", true),
+            ("GB", "GIR 0AA", true),
+            ("GB", "Q1A 1AA", false),
+            ("GB", "SW1A 1A", false),
+            ("CA", "K1A 0B1", true),
+            ("CA", "k1a0b1", true),
+            ("CA", "D1A 0B1", false),
+            ("CA", "K1O 0B1", false),
+            ("IE", "D02 X285", true),
+            ("IE", "D6W 1F23", true),
+            ("IE", "B02 X285", false),
+            ("IE", "D02 X28", false),
+            ("NL", "1012 AB", true),
+            ("NL", "1012ab", true),
+            ("NL", "1012 A", false),
+            ("NL", "101 AB", false),
+            ("US", "94043", true),
+            ("US", "94043-1351", true),
+            ("US", "9404", false),
+            ("US", "94043-13", false),
+            ("JP", "1000001", true),
+            ("FR", "75 001", true),
+            ("FR", "7500", false),
+            ("LU", "L-1111", true),
+            ("PT", "1100148", false),
+            ("", "anything", true),
+            ("ZZ", "anything", true),
+        ];
+        for (country, code, valid) in cases {
+            let format = AddressFormat::for_country(country);
+            assert_eq!(
+                format.validate_postal_code(code).is_ok(),
+                valid,
+                "{country} {code}"
+            );
+        }
+    }
+
+    #[test]
+    fn fields_are_required_once_any_is_filled() {
+        let us = AddressFormat::for_country("us");
+        let empty = Address::new("US");
+        assert!(us.validate(&empty, false).is_ok());
+        let error = us.validate_field(&empty, City, true).unwrap_err();
+        assert_eq!(error.key(), Some("required"));
+
+        let partial = address("US", "1600 Amphitheatre Pkwy", "", "", "");
+        for field in [City, Region, PostalCode] {
+            let error = us.validate_field(&partial, field, false).unwrap_err();
+            assert_eq!(error.key(), Some("required"), "{field:?}");
+        }
+        assert!(us.validate_field(&partial, StreetLines, false).is_ok());
+
+        // Optional fields may stay empty.
+        let ie = AddressFormat::for_country("IE");
+        let dublin = address("IE", "1 Main Street", "Dublin", "Co. Dublin", "");
+        assert!(ie.validate(&dublin, true).is_ok());
+        let paris = address("FR", "1 Rue de Rivoli", "Paris", "", "75001");
+        assert!(AddressFormat::for_country("FR")
+            .validate(&paris, true)
+            .is_ok());
+
+        let invalid = address(
+            "US",
+            "1600 Amphitheatre Pkwy",
+            "Mountain View",
+            "CA",
+            "9404",
+        );
+        let error = us.validate(&invalid, false).unwrap_err();
+        assert_eq!(error.key(), Some("address.invalid_postal_code"));
+    }
+
+    #[test]
+    fn single_line_follows_the_country() {
+        let us = address(
+            "US",
+            "1600 Amphitheatre Pkwy",
+            "Mountain View",
+            "CA",
+            "94043",
+        );
+        assert_eq!(
+            us.single_line(),
+            "1600 Amphitheatre Pkwy, Mountain View CA 94043, United States"
+        );
+        let fr = address("FR", "1 Rue de Rivoli", "Paris", "", "75001");
+        assert_eq!(fr.single_line(), "1 Rue de Rivoli, 75001 Paris, France");
+        let jp = address("JP", "1-1 Chiyoda", "Chiyoda-ku", "Tokyo", "100-0001");
+        assert_eq!(
+            jp.single_line(),
+            "100-0001, Tokyo, Chiyoda-ku, 1-1 Chiyoda, Japan"
+        );
+        let generic = address("", "1 Main St", "Springfield", "", "");
+        assert_eq!(generic.single_line(), "1 Main St, Springfield");
+    }
+}
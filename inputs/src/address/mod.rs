@@ -0,0 +1,8 @@
+mod format;
+mod postal_address;
+mod provider;
+
+pub use format::{country_name, AddressField, AddressFormat};
+pub use postal_address::Address;
+pub use provider::{AddressFuture, AddressProvider, AddressSuggestion, AnyAddressProvider};
+pub use provider::{HttpAddressProvider, InMemoryAddressProvider};
@@ -0,0 +1,88 @@
+use super::{country_name, AddressField, AddressFormat};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// A postal address, split in the fields of its country.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Address {
+    /// The street, the building and the apartment, one per line
+    pub street_lines: Vec<String>,
+    pub city: String,
+    /// The state, the province, the county or the prefecture
+    pub region: String,
+    pub postal_code: String,
+    /// The ISO 3166-1 alpha-2 code of the country, like `US`
+    pub country: String,
+}
+
+impl Address {
+    /// An empty address in the country.
+    pub fn new(country: impl Into<String>) -> Self {
+        Address {
+            country: country.into(),
+            ..Default::default()
+        }
+    }
+
+    /// The format of the country of the address.
+    pub fn format(&self) -> AddressFormat {
+        AddressFormat::for_country(&self.country)
+    }
+
+    /// The value of the field, with the street lines separated by commas.
+    pub fn value(&self, field: AddressField) -> String {
+        match field {
+            AddressField::StreetLines => self.street_lines().collect::<Vec<_>>().join(", "),
+            AddressField::City => self.city.clone(),
+            AddressField::Region => self.region.clone(),
+            AddressField::PostalCode => self.postal_code.clone(),
+            AddressField::Country => self.country.clone(),
+        }
+    }
+
+    /// Whether none of the fields but the country is filled.
+    pub fn is_empty(&self) -> bool {
+        self.street_lines().next().is_none()
+            && self.city.trim().is_empty()
+            && self.region.trim().is_empty()
+            && self.postal_code.trim().is_empty()
+    }
+
+    /// The address on a single line, in the order of its country, like
+    /// `1600 Amphitheatre Pkwy, Mountain View CA 94043, United States`.
+    ///
+    /// The name of the country is written in the current locale.
+    pub fn single_line(&self) -> String {
+        let mut parts: Vec<String> = self
+            .format()
+            .rows()
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|field| self.value(*field))
+                    .filter(|value| !value.trim().is_empty())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .filter(|line| !line.is_empty())
+            .collect();
+        if !self.country.trim().is_empty() {
+            parts.push(country_name(&self.country));
+        }
+        parts.join(", ")
+    }
+
+    fn street_lines(&self) -> impl Iterator<Item = &str> {
+        self.street_lines
+            .iter()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+    }
+}
+
+/// Writes the address on a single line, see [`Address::single_line`].
+impl Display for Address {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.single_line())
+    }
+}
@@ -0,0 +1,94 @@
+use super::{AddressFuture, AddressProvider, AddressSuggestion};
+use gloo_net::http::Request;
+use web_sys::AbortSignal;
+
+/// Queries a geocoding service over HTTP, like a thin endpoint in front of your own service.
+///
+/// The query is sent as `GET {base_url}?q={query}&limit={limit}`, followed by the extra
+/// parameters of the provider. The service answers with a JSON array of
+/// [`AddressSuggestion`]. A request still running when the same input queries the next one
+/// is aborted.
+#[derive(Clone, Debug)]
+pub struct HttpAddressProvider {
+    base_url: String,
+    query_param: String,
+    limit: usize,
+    params: Vec<(String, String)>,
+    headers: Vec<(String, String)>,
+}
+
+impl HttpAddressProvider {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        HttpAddressProvider {
+            base_url: base_url.into(),
+            query_param: String::from("q"),
+            limit: 5,
+            params: Vec::new(),
+            headers: Vec::new(),
+        }
+    }
+
+    /// The name of the query parameter, defaults to `q`.
+    pub fn query_param(mut self, name: impl Into<String>) -> Self {
+        self.query_param = name.into();
+        self
+    }
+
+    /// The maximal number of suggestions requested, defaults to 5.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Adds a query parameter to every request, like the country to search in.
+    pub fn param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.params.push((name.into(), value.into()));
+        self
+    }
+
+    /// Adds a header to every request, like an API key.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+impl HttpAddressProvider {
+    fn request(&self, query: String, signal: Option<&AbortSignal>) -> AddressFuture {
+        let limit = self.limit.to_string();
+        let params = [(self.query_param.as_str(), query.as_str()), ("limit", limit.as_str())]
+            .into_iter()
+            .chain(self.params.iter().map(|(k, v)| (k.as_str(), v.as_str())));
+        let mut request = Request::get(&self.base_url)
+            .query(params)
+            .abort_signal(signal);
+        for (name, value) in &self.headers {
+            request = request.header(name, value);
+        }
+        let base_url = self.base_url.clone();
+
+        Box::pin(async move {
+            let response = request.send().await.map_err(|e| e.to_string())?;
+            if !response.ok() {
+                return Err(format!(
+                    "{base_url} answered with status {}",
+                    response.status()
+                ));
+            }
+            response
+                .json::<Vec<AddressSuggestion>>()
+                .await
+                .map_err(|e| e.to_string())
+        })
+    }
+}
+
+impl AddressProvider for HttpAddressProvider {
+    fn suggest(&self, query: String) -> AddressFuture {
+        self.request(query, None)
+    }
+
+    fn suggest_abortable(&self, query: String, signal: &AbortSignal) -> AddressFuture {
+        self.request(query, Some(signal))
+    }
+}
@@ -0,0 +1,108 @@
+use super::{AddressFuture, AddressProvider, AddressSuggestion};
+use std::future::ready;
+use std::rc::Rc;
+
+/// Suggests addresses from a list held in memory, like the saved addresses of a user,
+/// or fixed addresses in tests.
+///
+/// An address matches when its label contains every word of the query, ignoring the case.
+#[derive(Clone, Debug)]
+pub struct InMemoryAddressProvider {
+    suggestions: Rc<Vec<AddressSuggestion>>,
+    limit: usize,
+}
+
+impl InMemoryAddressProvider {
+    /// Suggests the addresses, labelled by their single line form when given as an [`Address`](crate::Address).
+    pub fn new<I, S>(suggestions: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<AddressSuggestion>,
+    {
+        InMemoryAddressProvider {
+            suggestions: Rc::new(suggestions.into_iter().map(Into::into).collect()),
+            limit: 5,
+        }
+    }
+
+    /// The maximal number of suggestions, defaults to 5.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// The suggestions matching the query.
+    pub fn matching(&self, query: &str) -> Vec<AddressSuggestion> {
+        let words: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+        self.suggestions
+            .iter()
+            .filter(|suggestion| {
+                let label = suggestion.label.to_lowercase();
+                words.iter().all(|word| label.contains(word.as_str()))
+            })
+            .take(self.limit)
+            .cloned()
+            .collect()
+    }
+}
+
+impl AddressProvider for InMemoryAddressProvider {
+    fn suggest(&self, query: String) -> AddressFuture {
+        Box::pin(ready(Ok(self.matching(&query))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn provider() -> InMemoryAddressProvider {
+        InMemoryAddressProvider::new([
+            AddressSuggestion::from_label("10 Downing Street, London"),
+            AddressSuggestion::from_label("221B Baker Street, London"),
+            AddressSuggestion::from_label("1 Rue de Rivoli, Paris"),
+        ])
+    }
+
+    fn labels(suggestions: Vec<AddressSuggestion>) -> Vec<String> {
+        suggestions.into_iter().map(|s| s.label).collect()
+    }
+
+    #[test]
+    fn matches_every_word_ignoring_the_case() {
+        let provider = provider();
+        assert_eq!(
+            labels(provider.matching("street LONDON")),
+            ["10 Downing Street, London", "221B Baker Street, London"]
+        );
+        assert_eq!(
+            labels(provider.matching("  baker   london ")),
+            ["221B Baker Street, London"]
+        );
+        assert!(provider.matching("baker paris").is_empty());
+    }
+
+    #[test]
+    fn an_empty_query_matches_everything_up_to_the_limit() {
+        assert_eq!(provider().matching("").len(), 3);
+        assert_eq!(
+            labels(provider().limit(1).matching("street")),
+            ["10 Downing Street, London"]
+        );
+    }
+
+    #[test]
+    fn addresses_are_labelled_by_their_single_line() {
+        let address = crate::Address {
+            street_lines: vec![String::from("1 Rue de Rivoli")],
+            city: String::from("Paris"),
+            postal_code: String::from("75001"),
+            ..Default::default()
+        };
+        let provider = InMemoryAddressProvider::new([address.clone()]);
+        let suggestions = provider.matching("rivoli");
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].label, address.single_line());
+        assert_eq!(suggestions[0].address, address);
+    }
+}
@@ -0,0 +1,7 @@
+mod http;
+mod memory;
+mod source;
+
+pub use http::HttpAddressProvider;
+pub use memory::InMemoryAddressProvider;
+pub use source::{AddressFuture, AddressProvider, AddressSuggestion, AnyAddressProvider};
@@ -0,0 +1,202 @@
+use crate::{Address, SuggestionSource};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::time::Duration;
+use web_sys::{AbortController, AbortSignal};
+
+/// An address suggested while the user types, with the text displayed in the list.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressSuggestion {
+    pub label: String,
+    pub address: Address,
+}
+
+impl AddressSuggestion {
+    /// A suggestion of a text only, without a structured address.
+    pub fn from_label(label: impl Into<String>) -> Self {
+        AddressSuggestion {
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<Address> for AddressSuggestion {
+    fn from(address: Address) -> Self {
+        AddressSuggestion {
+            label: address.single_line(),
+            address,
+        }
+    }
+}
+
+/// The future returned by an [`AddressProvider`].
+pub type AddressFuture = Pin<Box<dyn Future<Output = Result<Vec<AddressSuggestion>, String>>>>;
+
+/// A source of addresses matching the text typed in an address input, like a geocoding service.
+///
+/// Any closure `Fn(String) -> impl Future<Output = Result<Vec<AddressSuggestion>, String>>`
+/// is a provider.
+pub trait AddressProvider {
+    /// The addresses matching the query, or the reason they could not be fetched.
+    fn suggest(&self, query: String) -> AddressFuture;
+
+    /// The addresses matching the query, cancelling the request once the signal is aborted.
+    ///
+    /// Defaults to [`suggest`](Self::suggest), ignoring the signal.
+    fn suggest_abortable(&self, query: String, signal: &AbortSignal) -> AddressFuture {
+        let _ = signal;
+        self.suggest(query)
+    }
+}
+
+impl<F, Fut> AddressProvider for F
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<Vec<AddressSuggestion>, String>> + 'static,
+{
+    fn suggest(&self, query: String) -> AddressFuture {
+        Box::pin(self(query))
+    }
+}
+
+/// A type erased [`AddressProvider`], as accepted by the inputs.
+///
+/// The provider is queried once the text stopped changing for the debounce delay, and only
+/// if it is long enough. Results of a text that changed in the meantime are discarded, and
+/// each input aborts its own request still running when it sends the next one.
+#[derive(Clone)]
+pub struct AnyAddressProvider {
+    provider: Rc<dyn AddressProvider>,
+    debounce: Duration,
+    min_query_len: usize,
+}
+
+impl AnyAddressProvider {
+    /// The default delay between the last change of the text and the query.
+    pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(250);
+    /// The default number of characters typed before the provider is queried.
+    pub const DEFAULT_MIN_QUERY_LEN: usize = 3;
+
+    pub fn new(provider: impl AddressProvider + 'static) -> Self {
+        AnyAddressProvider {
+            provider: Rc::new(provider),
+            debounce: Self::DEFAULT_DEBOUNCE,
+            min_query_len: Self::DEFAULT_MIN_QUERY_LEN,
+        }
+    }
+
+    /// Sets the delay between the last change of the text and the query.
+    pub fn debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Sets the number of characters typed before the provider is queried.
+    pub fn min_query_len(mut self, len: usize) -> Self {
+        self.min_query_len = len;
+        self
+    }
+
+    pub fn debounce_delay(&self) -> Duration {
+        self.debounce
+    }
+
+    /// Whether the query is long enough to be sent to the provider.
+    pub fn accepts(&self, query: &str) -> bool {
+        query.trim().chars().count() >= self.min_query_len
+    }
+
+    pub fn suggest(&self, query: String) -> AddressFuture {
+        self.provider.suggest(query)
+    }
+}
+
+impl<P: AddressProvider + 'static> From<P> for AnyAddressProvider {
+    fn from(provider: P) -> Self {
+        AnyAddressProvider::new(provider)
+    }
+}
+
+impl From<AnyAddressProvider> for SuggestionSource<AddressSuggestion> {
+    fn from(provider: AnyAddressProvider) -> Self {
+        let in_flight = RefCell::new(None::<AbortController>);
+        SuggestionSource::Fetch {
+            debounce: provider.debounce,
+            min_query_len: provider.min_query_len,
+            fetch: Rc::new(move |query| {
+                let controller = AbortController::new().ok();
+                if let Some(previous) = in_flight.replace(controller.clone()) {
+                    previous.abort();
+                }
+                match controller {
+                    Some(controller) => provider
+                        .provider
+                        .suggest_abortable(query, &controller.signal()),
+                    None => provider.suggest(query),
+                }
+            }),
+        }
+    }
+}
+
+impl Debug for AnyAddressProvider {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AnyAddressProvider")
+            .field("debounce", &self.debounce)
+            .field("min_query_len", &self.min_query_len)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::future::ready;
+
+    fn provider() -> AnyAddressProvider {
+        AnyAddressProvider::new(|_: String| ready(Ok(Vec::new())))
+    }
+
+    #[test]
+    fn accepts_queries_from_the_min_length() {
+        let provider = provider();
+        assert!(!provider.accepts("ab"));
+        assert!(provider.accepts("abc"));
+        let provider = provider.min_query_len(5);
+        assert!(!provider.accepts("  abcd  "));
+        assert!(provider.accepts("abcde"));
+        // Characters are counted, not bytes.
+        assert!(!provider.accepts("éèà"));
+        assert!(provider.accepts("éèàçù"));
+        assert!(self::provider().min_query_len(0).accepts(""));
+    }
+
+    #[test]
+    fn debounce_defaults_and_is_passed_to_the_source() {
+        let provider = provider();
+        assert_eq!(
+            provider.debounce_delay(),
+            AnyAddressProvider::DEFAULT_DEBOUNCE
+        );
+        let provider = provider
+            .debounce(Duration::from_millis(40))
+            .min_query_len(2);
+        assert_eq!(provider.debounce_delay(), Duration::from_millis(40));
+        match SuggestionSource::from(provider) {
+            SuggestionSource::Fetch {
+                debounce,
+                min_query_len,
+                ..
+            } => {
+                assert_eq!(debounce, Duration::from_millis(40));
+                assert_eq!(min_query_len, 2);
+            }
+            _ => panic!("an address provider is fetched"),
+        }
+    }
+}
@@ -0,0 +1,465 @@
+use std::collections::HashMap;
+
+/// The translated messages of a locale.
+///
+/// The messages of the crate are bundled for `en`, `fr` and `de`. A catalog registered
+/// on the [`I18n`](crate::I18n) context adds a locale, or overrides some of its messages.
+/// Parameters are written between braces, for example `{min}`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Catalog {
+    locale: String,
+    messages: HashMap<String, String>,
+}
+
+impl Catalog {
+    pub fn new(locale: impl Into<String>) -> Self {
+        Catalog {
+            locale: locale.into(),
+            messages: HashMap::new(),
+        }
+    }
+
+    /// Adds the message of the key, see [`Catalog::insert`].
+    pub fn with(mut self, key: impl Into<String>, message: impl Into<String>) -> Self {
+        self.insert(key, message);
+        self
+    }
+
+    /// Adds the message of the key, replacing the previous one.
+    pub fn insert(&mut self, key: impl Into<String>, message: impl Into<String>) {
+        self.messages.insert(key.into(), message.into());
+    }
+
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.messages.get(key).map(String::as_str)
+    }
+}
+
+/// The messages bundled with the crate for the locale.
+pub(crate) fn builtin(locale: &str, key: &str) -> Option<&'static str> {
+    let messages = match locale {
+        "en" => EN,
+        "fr" => FR,
+        "de" => DE,
+        _ => return None,
+    };
+    messages.iter().find(|(k, _)| *k == key).map(|(_, m)| *m)
+}
+
+const EN: &[(&str, &str)] = &[
+    ("required", "This field is required"),
+    ("invalid", "This field is invalid."),
+    ("min_len", "This field must be at least {min} characters long."),
+    ("max_len", "This field must be at most {max} characters long."),
+    ("pattern", "This field has an invalid format."),
+    ("one_of", "This field must be one of: {values}."),
+    ("email", "Please enter a valid email address."),
+    ("email.did_you_mean", "Did you mean {suggestion}?"),
+    ("email.domain_not_allowed", "Please use an address of {domains}."),
+    ("email.domain_denied", "Addresses of {domain} are not accepted."),
+    ("email.disposable", "Disposable email addresses are not accepted."),
+    ("email.remove", "Remove {email}"),
+    ("autocomplete.no_match", "Please choose one of the suggestions."),
+    ("number.invalid", "Please enter a number."),
+    ("number.min", "The value must be at least {min}."),
+    ("number.max", "The value must be at most {max}."),
+    ("number.step", "The value must be a multiple of {step}."),
+    ("number.increment", "Increase"),
+    ("number.decrement", "Decrease"),
+    ("date.invalid", "Please enter a date like {example}."),
+    ("date.min", "The date must be {min} or later."),
+    ("date.max", "The date must be {max} or earlier."),
+    ("date.unavailable", "This date is not available."),
+    ("date.choose", "Choose a date"),
+    ("date.previous_month", "Previous month"),
+    ("date.next_month", "Next month"),
+    ("date.previous_year", "Previous year"),
+    ("date.next_year", "Next year"),
+    ("date.month.1", "January"),
+    ("date.month.2", "February"),
+    ("date.month.3", "March"),
+    ("date.month.4", "April"),
+    ("date.month.5", "May"),
+    ("date.month.6", "June"),
+    ("date.month.7", "July"),
+    ("date.month.8", "August"),
+    ("date.month.9", "September"),
+    ("date.month.10", "October"),
+    ("date.month.11", "November"),
+    ("date.month.12", "December"),
+    ("date.weekday.mon", "Monday"),
+    ("date.weekday.tue", "Tuesday"),
+    ("date.weekday.wed", "Wednesday"),
+    ("date.weekday.thu", "Thursday"),
+    ("date.weekday.fri", "Friday"),
+    ("date.weekday.sat", "Saturday"),
+    ("date.weekday.sun", "Sunday"),
+    ("date.weekday_short.mon", "Mo"),
+    ("date.weekday_short.tue", "Tu"),
+    ("date.weekday_short.wed", "We"),
+    ("date.weekday_short.thu", "Th"),
+    ("date.weekday_short.fri", "Fr"),
+    ("date.weekday_short.sat", "Sa"),
+    ("date.weekday_short.sun", "Su"),
+    ("matches", "The values do not match."),
+    ("password.mismatch", "The passwords do not match."),
+    ("password.weak", "The password must be at least {min} characters long, and contain at least one lowercase letter, one uppercase letter."),
+    ("password.medium", "The password must be at least {min} characters long, and contain at least one lowercase letter, one uppercase letter, one number and one special character [ {special} ]."),
+    ("password.strong", "The password must be at least {min} characters long, and contain at least one lowercase letter, one uppercase letter, one number and one special character [ {special} ]."),
+    ("password.min_len", "At least {min} characters"),
+    ("password.lowercase", "One lowercase letter"),
+    ("password.uppercase", "One uppercase letter"),
+    ("password.number", "One number"),
+    ("password.special", "One special character [ {special} ]"),
+    ("password.max_len", "At most {max} characters"),
+    ("password.lowercase_count", "At least {count} lowercase letters"),
+    ("password.uppercase_count", "At least {count} uppercase letters"),
+    ("password.number_count", "At least {count} numbers"),
+    ("password.special_count", "At least {count} special characters [ {special} ]"),
+    ("password.max_repeated", "No character more than {max} times in a row"),
+    ("password.not_containing", "Does not contain \"{value}\""),
+    ("password.denied", "Not a forbidden password"),
+    ("password.show", "Show password"),
+    ("password.hide", "Hide password"),
+    ("password.caps_lock", "Caps lock is on"),
+    ("password.breached", "This password appeared in a data breach, please choose another one."),
+    ("password.generate", "Generate a password"),
+    ("password.copy", "Copy the password"),
+    ("password.copied", "Password copied"),
+    ("password.too_guessable", "The password is too easy to guess."),
+    ("strength.very_weak", "Very weak"),
+    ("strength.weak", "Weak"),
+    ("strength.fair", "Fair"),
+    ("strength.strong", "Strong"),
+    ("strength.very_strong", "Very strong"),
+    ("address.street", "Street address"),
+    ("address.street2", "Apartment, suite, etc."),
+    ("address.city", "City"),
+    ("address.town", "Town/City"),
+    ("address.suburb", "Suburb"),
+    ("address.region", "Region"),
+    ("address.state", "State"),
+    ("address.province", "Province"),
+    ("address.county", "County"),
+    ("address.prefecture", "Prefecture"),
+    ("address.postal_code", "Postal code"),
+    ("address.postcode", "Postcode"),
+    ("address.zip", "ZIP code"),
+    ("address.eircode", "Eircode"),
+    ("address.pin", "PIN code"),
+    ("address.cep", "CEP"),
+    ("address.country", "Country"),
+    ("address.invalid_postal_code", "Please enter a valid postal code, like {example}."),
+    ("country.AT", "Austria"),
+    ("country.AU", "Australia"),
+    ("country.BE", "Belgium"),
+    ("country.BR", "Brazil"),
+    ("country.CA", "Canada"),
+    ("country.CH", "Switzerland"),
+    ("country.DE", "Germany"),
+    ("country.DK", "Denmark"),
+    ("country.ES", "Spain"),
+    ("country.FR", "France"),
+    ("country.GB", "United Kingdom"),
+    ("country.IE", "Ireland"),
+    ("country.IN", "India"),
+    ("country.IT", "Italy"),
+    ("country.JP", "Japan"),
+    ("country.LU", "Luxembourg"),
+    ("country.MX", "Mexico"),
+    ("country.NL", "Netherlands"),
+    ("country.NO", "Norway"),
+    ("country.NZ", "New Zealand"),
+    ("country.PT", "Portugal"),
+    ("country.SE", "Sweden"),
+    ("country.US", "United States"),
+    ("label.email", "Email"),
+    ("label.emails", "Emails"),
+    ("label.password", "Password"),
+    ("label.password_confirm", "Confirm password"),
+    ("label.address", "Address"),
+    ("label.date", "Date"),
+    ("button.submit", "Submit"),
+    ("form.login", "Login"),
+    ("popup.close", "Close"),
+];
+
+const FR: &[(&str, &str)] = &[
+    ("required", "Ce champ est obligatoire"),
+    ("invalid", "Ce champ est invalide."),
+    ("min_len", "Ce champ doit contenir au moins {min} caractères."),
+    ("max_len", "Ce champ doit contenir au plus {max} caractères."),
+    ("pattern", "Ce champ a un format invalide."),
+    ("one_of", "Ce champ doit être l'une des valeurs suivantes : {values}."),
+    ("email", "Veuillez saisir une adresse e-mail valide."),
+    ("email.did_you_mean", "Vouliez-vous dire {suggestion} ?"),
+    ("email.domain_not_allowed", "Veuillez utiliser une adresse de {domains}."),
+    ("email.domain_denied", "Les adresses de {domain} ne sont pas acceptées."),
+    ("email.disposable", "Les adresses e-mail jetables ne sont pas acceptées."),
+    ("email.remove", "Retirer {email}"),
+    ("autocomplete.no_match", "Veuillez choisir une des suggestions."),
+    ("number.invalid", "Veuillez saisir un nombre."),
+    ("number.min", "La valeur doit être au moins {min}."),
+    ("number.max", "La valeur doit être au plus {max}."),
+    ("number.step", "La valeur doit être un multiple de {step}."),
+    ("number.increment", "Augmenter"),
+    ("number.decrement", "Diminuer"),
+    ("date.invalid", "Veuillez saisir une date comme {example}."),
+    ("date.min", "La date doit être le {min} ou après."),
+    ("date.max", "La date doit être le {max} ou avant."),
+    ("date.unavailable", "Cette date n'est pas disponible."),
+    ("date.choose", "Choisir une date"),
+    ("date.previous_month", "Mois précédent"),
+    ("date.next_month", "Mois suivant"),
+    ("date.previous_year", "Année précédente"),
+    ("date.next_year", "Année suivante"),
+    ("date.month.1", "janvier"),
+    ("date.month.2", "février"),
+    ("date.month.3", "mars"),
+    ("date.month.4", "avril"),
+    ("date.month.5", "mai"),
+    ("date.month.6", "juin"),
+    ("date.month.7", "juillet"),
+    ("date.month.8", "août"),
+    ("date.month.9", "septembre"),
+    ("date.month.10", "octobre"),
+    ("date.month.11", "novembre"),
+    ("date.month.12", "décembre"),
+    ("date.weekday.mon", "lundi"),
+    ("date.weekday.tue", "mardi"),
+    ("date.weekday.wed", "mercredi"),
+    ("date.weekday.thu", "jeudi"),
+    ("date.weekday.fri", "vendredi"),
+    ("date.weekday.sat", "samedi"),
+    ("date.weekday.sun", "dimanche"),
+    ("date.weekday_short.mon", "lu"),
+    ("date.weekday_short.tue", "ma"),
+    ("date.weekday_short.wed", "me"),
+    ("date.weekday_short.thu", "je"),
+    ("date.weekday_short.fri", "ve"),
+    ("date.weekday_short.sat", "sa"),
+    ("date.weekday_short.sun", "di"),
+    ("matches", "Les valeurs ne correspondent pas."),
+    ("password.mismatch", "Les mots de passe ne correspondent pas."),
+    ("password.weak", "Le mot de passe doit contenir au moins {min} caractères, dont au moins une lettre minuscule et une lettre majuscule."),
+    ("password.medium", "Le mot de passe doit contenir au moins {min} caractères, dont au moins une lettre minuscule, une lettre majuscule, un chiffre et un caractère spécial [ {special} ]."),
+    ("password.strong", "Le mot de passe doit contenir au moins {min} caractères, dont au moins une lettre minuscule, une lettre majuscule, un chiffre et un caractère spécial [ {special} ]."),
+    ("password.min_len", "Au moins {min} caractères"),
+    ("password.lowercase", "Une lettre minuscule"),
+    ("password.uppercase", "Une lettre majuscule"),
+    ("password.number", "Un chiffre"),
+    ("password.special", "Un caractère spécial [ {special} ]"),
+    ("password.max_len", "Au plus {max} caractères"),
+    ("password.lowercase_count", "Au moins {count} lettres minuscules"),
+    ("password.uppercase_count", "Au moins {count} lettres majuscules"),
+    ("password.number_count", "Au moins {count} chiffres"),
+    ("password.special_count", "Au moins {count} caractères spéciaux [ {special} ]"),
+    ("password.max_repeated", "Aucun caractère plus de {max} fois de suite"),
+    ("password.not_containing", "Ne contient pas « {value} »"),
+    ("password.denied", "Pas un mot de passe interdit"),
+    ("password.show", "Afficher le mot de passe"),
+    ("password.hide", "Masquer le mot de passe"),
+    ("password.caps_lock", "La touche Verr. Maj est activée"),
+    ("password.breached", "Ce mot de passe est apparu dans une fuite de données, veuillez en choisir un autre."),
+    ("password.generate", "Générer un mot de passe"),
+    ("password.copy", "Copier le mot de passe"),
+    ("password.copied", "Mot de passe copié"),
+    ("password.too_guessable", "Le mot de passe est trop facile à deviner."),
+    ("strength.very_weak", "Très faible"),
+    ("strength.weak", "Faible"),
+    ("strength.fair", "Moyen"),
+    ("strength.strong", "Fort"),
+    ("strength.very_strong", "Très fort"),
+    ("address.street", "Adresse"),
+    ("address.street2", "Appartement, étage, etc."),
+    ("address.city", "Ville"),
+    ("address.town", "Ville"),
+    ("address.suburb", "Localité"),
+    ("address.region", "Région"),
+    ("address.state", "État"),
+    ("address.province", "Province"),
+    ("address.county", "Comté"),
+    ("address.prefecture", "Préfecture"),
+    ("address.postal_code", "Code postal"),
+    ("address.postcode", "Code postal"),
+    ("address.zip", "Code ZIP"),
+    ("address.eircode", "Eircode"),
+    ("address.pin", "Code PIN"),
+    ("address.cep", "CEP"),
+    ("address.country", "Pays"),
+    ("address.invalid_postal_code", "Veuillez saisir un code postal valide, par exemple {example}."),
+    ("country.AT", "Autriche"),
+    ("country.AU", "Australie"),
+    ("country.BE", "Belgique"),
+    ("country.BR", "Brésil"),
+    ("country.CA", "Canada"),
+    ("country.CH", "Suisse"),
+    ("country.DE", "Allemagne"),
+    ("country.DK", "Danemark"),
+    ("country.ES", "Espagne"),
+    ("country.FR", "France"),
+    ("country.GB", "Royaume-Uni"),
+    ("country.IE", "Irlande"),
+    ("country.IN", "Inde"),
+    ("country.IT", "Italie"),
+    ("country.JP", "Japon"),
+    ("country.LU", "Luxembourg"),
+    ("country.MX", "Mexique"),
+    ("country.NL", "Pays-Bas"),
+    ("country.NO", "Norvège"),
+    ("country.NZ", "Nouvelle-Zélande"),
+    ("country.PT", "Portugal"),
+    ("country.SE", "Suède"),
+    ("country.US", "États-Unis"),
+    ("label.email", "E-mail"),
+    ("label.emails", "E-mails"),
+    ("label.password", "Mot de passe"),
+    ("label.password_confirm", "Confirmer le mot de passe"),
+    ("label.address", "Adresse"),
+    ("label.date", "Date"),
+    ("button.submit", "Envoyer"),
+    ("form.login", "Connexion"),
+    ("popup.close", "Fermer"),
+];
+
+const DE: &[(&str, &str)] = &[
+    ("required", "Dieses Feld ist erforderlich"),
+    ("invalid", "Dieses Feld ist ungültig."),
+    ("min_len", "Dieses Feld muss mindestens {min} Zeichen lang sein."),
+    ("max_len", "Dieses Feld darf höchstens {max} Zeichen lang sein."),
+    ("pattern", "Dieses Feld hat ein ungültiges Format."),
+    ("one_of", "Dieses Feld muss einer der folgenden Werte sein: {values}."),
+    ("email", "Bitte geben Sie eine gültige E-Mail-Adresse ein."),
+    ("email.did_you_mean", "Meinten Sie {suggestion}?"),
+    ("email.domain_not_allowed", "Bitte verwenden Sie eine Adresse von {domains}."),
+    ("email.domain_denied", "Adressen von {domain} werden nicht akzeptiert."),
+    ("email.disposable", "Wegwerf-E-Mail-Adressen werden nicht akzeptiert."),
+    ("email.remove", "{email} entfernen"),
+    ("autocomplete.no_match", "Bitte wählen Sie einen der Vorschläge."),
+    ("number.invalid", "Bitte geben Sie eine Zahl ein."),
+    ("number.min", "Der Wert muss mindestens {min} betragen."),
+    ("number.max", "Der Wert darf höchstens {max} betragen."),
+    ("number.step", "Der Wert muss ein Vielfaches von {step} sein."),
+    ("number.increment", "Erhöhen"),
+    ("number.decrement", "Verringern"),
+    ("date.invalid", "Bitte geben Sie ein Datum wie {example} ein."),
+    ("date.min", "Das Datum muss der {min} oder später sein."),
+    ("date.max", "Das Datum muss der {max} oder früher sein."),
+    ("date.unavailable", "Dieses Datum ist nicht verfügbar."),
+    ("date.choose", "Datum auswählen"),
+    ("date.previous_month", "Vorheriger Monat"),
+    ("date.next_month", "Nächster Monat"),
+    ("date.previous_year", "Vorheriges Jahr"),
+    ("date.next_year", "Nächstes Jahr"),
+    ("date.month.1", "Januar"),
+    ("date.month.2", "Februar"),
+    ("date.month.3", "März"),
+    ("date.month.4", "April"),
+    ("date.month.5", "Mai"),
+    ("date.month.6", "Juni"),
+    ("date.month.7", "Juli"),
+    ("date.month.8", "August"),
+    ("date.month.9", "September"),
+    ("date.month.10", "Oktober"),
+    ("date.month.11", "November"),
+    ("date.month.12", "Dezember"),
+    ("date.weekday.mon", "Montag"),
+    ("date.weekday.tue", "Dienstag"),
+    ("date.weekday.wed", "Mittwoch"),
+    ("date.weekday.thu", "Donnerstag"),
+    ("date.weekday.fri", "Freitag"),
+    ("date.weekday.sat", "Samstag"),
+    ("date.weekday.sun", "Sonntag"),
+    ("date.weekday_short.mon", "Mo"),
+    ("date.weekday_short.tue", "Di"),
+    ("date.weekday_short.wed", "Mi"),
+    ("date.weekday_short.thu", "Do"),
+    ("date.weekday_short.fri", "Fr"),
+    ("date.weekday_short.sat", "Sa"),
+    ("date.weekday_short.sun", "So"),
+    ("matches", "Die Werte stimmen nicht überein."),
+    ("password.mismatch", "Die Passwörter stimmen nicht überein."),
+    ("password.weak", "Das Passwort muss mindestens {min} Zeichen lang sein und mindestens einen Kleinbuchstaben und einen Großbuchstaben enthalten."),
+    ("password.medium", "Das Passwort muss mindestens {min} Zeichen lang sein und mindestens einen Kleinbuchstaben, einen Großbuchstaben, eine Ziffer und ein Sonderzeichen [ {special} ] enthalten."),
+    ("password.strong", "Das Passwort muss mindestens {min} Zeichen lang sein und mindestens einen Kleinbuchstaben, einen Großbuchstaben, eine Ziffer und ein Sonderzeichen [ {special} ] enthalten."),
+    ("password.min_len", "Mindestens {min} Zeichen"),
+    ("password.lowercase", "Ein Kleinbuchstabe"),
+    ("password.uppercase", "Ein Großbuchstabe"),
+    ("password.number", "Eine Ziffer"),
+    ("password.special", "Ein Sonderzeichen [ {special} ]"),
+    ("password.max_len", "Höchstens {max} Zeichen"),
+    ("password.lowercase_count", "Mindestens {count} Kleinbuchstaben"),
+    ("password.uppercase_count", "Mindestens {count} Großbuchstaben"),
+    ("password.number_count", "Mindestens {count} Ziffern"),
+    ("password.special_count", "Mindestens {count} Sonderzeichen [ {special} ]"),
+    ("password.max_repeated", "Kein Zeichen mehr als {max} Mal hintereinander"),
+    ("password.not_containing", "Enthält nicht „{value}“"),
+    ("password.denied", "Kein verbotenes Passwort"),
+    ("password.show", "Passwort anzeigen"),
+    ("password.hide", "Passwort verbergen"),
+    ("password.caps_lock", "Die Feststelltaste ist aktiviert"),
+    ("password.breached", "Dieses Passwort ist in einem Datenleck aufgetaucht, bitte wählen Sie ein anderes."),
+    ("password.generate", "Passwort generieren"),
+    ("password.copy", "Passwort kopieren"),
+    ("password.copied", "Passwort kopiert"),
+    ("password.too_guessable", "Das Passwort ist zu leicht zu erraten."),
+    ("strength.very_weak", "Sehr schwach"),
+    ("strength.weak", "Schwach"),
+    ("strength.fair", "Mittel"),
+    ("strength.strong", "Stark"),
+    ("strength.very_strong", "Sehr stark"),
+    ("address.street", "Straße und Hausnummer"),
+    ("address.street2", "Wohnung, Etage usw."),
+    ("address.city", "Ort"),
+    ("address.town", "Ort"),
+    ("address.suburb", "Vorort"),
+    ("address.region", "Region"),
+    ("address.state", "Bundesstaat"),
+    ("address.province", "Provinz"),
+    ("address.county", "County"),
+    ("address.prefecture", "Präfektur"),
+    ("address.postal_code", "Postleitzahl"),
+    ("address.postcode", "Postleitzahl"),
+    ("address.zip", "ZIP-Code"),
+    ("address.eircode", "Eircode"),
+    ("address.pin", "PIN-Code"),
+    ("address.cep", "CEP"),
+    ("address.country", "Land"),
+    ("address.invalid_postal_code", "Bitte geben Sie eine gültige Postleitzahl ein, z. B. {example}."),
+    ("country.AT", "Österreich"),
+    ("country.AU", "Australien"),
+    ("country.BE", "Belgien"),
+    ("country.BR", "Brasilien"),
+    ("country.CA", "Kanada"),
+    ("country.CH", "Schweiz"),
+    ("country.DE", "Deutschland"),
+    ("country.DK", "Dänemark"),
+    ("country.ES", "Spanien"),
+    ("country.FR", "Frankreich"),
+    ("country.GB", "Vereinigtes Königreich"),
+    ("country.IE", "Irland"),
+    ("country.IN", "Indien"),
+    ("country.IT", "Italien"),
+    ("country.JP", "Japan"),
+    ("country.LU", "Luxemburg"),
+    ("country.MX", "Mexiko"),
+    ("country.NL", "Niederlande"),
+    ("country.NO", "Norwegen"),
+    ("country.NZ", "Neuseeland"),
+    ("country.PT", "Portugal"),
+    ("country.SE", "Schweden"),
+    ("country.US", "Vereinigte Staaten"),
+    ("label.email", "E-Mail"),
+    ("label.emails", "E-Mails"),
+    ("label.password", "Passwort"),
+    ("label.password_confirm", "Passwort bestätigen"),
+    ("label.address", "Adresse"),
+    ("label.date", "Datum"),
+    ("button.submit", "Absenden"),
+    ("form.login", "Anmelden"),
+    ("popup.close", "Schließen"),
+];
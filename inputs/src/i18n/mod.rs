@@ -0,0 +1,5 @@
+mod catalog;
+mod provider;
+
+pub use catalog::Catalog;
+pub use provider::{translate, I18n, I18nProvider};
@@ -0,0 +1,196 @@
+use crate::i18n::catalog::builtin;
+use crate::Catalog;
+use leptos::{component, provide_context, use_context, Children, IntoView};
+use leptos::{create_rw_signal, store_value, RwSignal, Signal, StoredValue};
+use leptos::{SignalGet, SignalSet};
+
+/// The locale of the messages displayed by the components, provided by [`I18nProvider`].
+///
+/// A message is looked up in the locale (`fr-CA`), its language (`fr`), then in the
+/// fallback locale and its language, and finally in English.
+/// The locale can be changed at runtime, the messages are updated accordingly.
+#[derive(Clone, Copy)]
+pub struct I18n {
+    locale: RwSignal<String>,
+    fallback: StoredValue<String>,
+    catalogs: StoredValue<Vec<Catalog>>,
+}
+
+impl I18n {
+    pub fn new(locale: impl Into<String>) -> Self {
+        I18n {
+            locale: create_rw_signal(locale.into()),
+            fallback: store_value(String::from("en")),
+            catalogs: store_value(Vec::new()),
+        }
+    }
+
+    /// Sets the locale used when a message is missing from the current locale, defaults to `en`.
+    pub fn with_fallback(self, locale: impl Into<String>) -> Self {
+        self.fallback.set_value(locale.into());
+        self
+    }
+
+    /// Adds a catalog, see [`I18n::add_catalog`].
+    pub fn with_catalog(self, catalog: Catalog) -> Self {
+        self.add_catalog(catalog);
+        self
+    }
+
+    /// Adds a catalog. Its messages take precedence over the ones of the
+    /// catalogs added before, and over the messages bundled with the crate.
+    pub fn add_catalog(&self, catalog: Catalog) {
+        self.catalogs.update_value(|c| c.push(catalog));
+    }
+
+    pub fn locale(&self) -> Signal<String> {
+        self.locale.into()
+    }
+
+    pub fn set_locale(&self, locale: impl Into<String>) {
+        self.locale.set(locale.into());
+    }
+
+    /// The message of the key in the current locale, with its parameters replaced.
+    /// Falls back to the key itself if no locale defines it.
+    pub fn translate(&self, key: &str, params: &[(&str, String)]) -> String {
+        let locale = self.locale.get();
+        let fallback = self.fallback.get_value();
+
+        let message = candidates(&locale)
+            .into_iter()
+            .chain(candidates(&fallback))
+            .chain(["en"])
+            .find_map(|locale| self.lookup(locale, key));
+
+        interpolate(message.as_deref().unwrap_or(key), params)
+    }
+
+    fn lookup(&self, locale: &str, key: &str) -> Option<String> {
+        self.catalogs
+            .with_value(|catalogs| {
+                catalogs
+                    .iter()
+                    .rev()
+                    .filter(|c| c.locale() == locale)
+                    .find_map(|c| c.get(key).map(String::from))
+            })
+            .or_else(|| builtin(locale, key).map(String::from))
+    }
+}
+
+/// The locale, followed by its language.
+fn candidates(locale: &str) -> [&str; 2] {
+    let language = locale.split(['-', '_']).next().unwrap_or(locale);
+    [locale, language]
+}
+
+fn interpolate(message: &str, params: &[(&str, String)]) -> String {
+    params
+        .iter()
+        .fold(message.to_string(), |message, (name, value)| {
+            message.replace(&format!("{{{name}}}"), value)
+        })
+}
+
+/// The message of the key in the locale of the [`I18n`] context, or in English if
+/// there is none. Reading it in a reactive closure updates it when the locale changes.
+pub fn translate(key: &str, params: &[(&str, String)]) -> String {
+    match use_context::<I18n>() {
+        Some(i18n) => i18n.translate(key, params),
+        None => interpolate(builtin("en", key).unwrap_or(key), params),
+    }
+}
+
+/// Provides the [`I18n`] context to the components.
+#[component]
+pub fn I18nProvider(
+    /// The translations. Pass it to change the locale at runtime
+    #[prop(optional)]
+    i18n: Option<I18n>,
+    /// The locale, used if no `i18n` is given, defaults to `en`
+    #[prop(optional, into)]
+    locale: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    let i18n = i18n.unwrap_or_else(|| I18n::new(locale.unwrap_or_else(|| String::from("en"))));
+    provide_context(i18n);
+
+    children()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::create_runtime;
+
+    fn i18n() -> I18n {
+        I18n::new("fr-CA")
+            .with_fallback("de-CH")
+            .with_catalog(Catalog::new("fr-CA").with("a", "fr-CA"))
+            .with_catalog(Catalog::new("fr").with("a", "fr").with("b", "fr"))
+            .with_catalog(Catalog::new("de-CH").with("c", "de-CH"))
+            .with_catalog(Catalog::new("de").with("c", "de").with("d", "de"))
+            .with_catalog(Catalog::new("en").with("e", "en"))
+    }
+
+    #[test]
+    fn falls_back_from_locale_to_language_to_fallback_to_english() {
+        let runtime = create_runtime();
+        let i18n = i18n();
+        assert_eq!(i18n.translate("a", &[]), "fr-CA");
+        assert_eq!(i18n.translate("b", &[]), "fr");
+        assert_eq!(i18n.translate("c", &[]), "de-CH");
+        assert_eq!(i18n.translate("d", &[]), "de");
+        assert_eq!(i18n.translate("e", &[]), "en");
+        assert_eq!(i18n.translate("missing", &[]), "missing");
+        runtime.dispose();
+    }
+
+    #[test]
+    fn catalogs_override_the_builtin_messages() {
+        let runtime = create_runtime();
+        let i18n = I18n::new("fr_FR");
+        assert_eq!(i18n.translate("required", &[]), "Ce champ est obligatoire");
+
+        i18n.add_catalog(Catalog::new("fr").with("required", "Obligatoire"));
+        assert_eq!(i18n.translate("required", &[]), "Obligatoire");
+        i18n.add_catalog(Catalog::new("fr").with("required", "Requis"));
+        assert_eq!(i18n.translate("required", &[]), "Requis");
+        runtime.dispose();
+    }
+
+    #[test]
+    fn follows_the_locale() {
+        let runtime = create_runtime();
+        let i18n = I18n::new("xx");
+        assert_eq!(i18n.translate("required", &[]), "This field is required");
+        i18n.set_locale("de");
+        assert_eq!(i18n.locale().get(), "de");
+        assert_eq!(
+            i18n.translate("required", &[]),
+            "Dieses Feld ist erforderlich"
+        );
+        runtime.dispose();
+    }
+
+    #[test]
+    fn english_without_context() {
+        let runtime = create_runtime();
+        assert_eq!(translate("required", &[]), "This field is required");
+        assert_eq!(
+            translate("missing {n}", &[("n", String::from("1"))]),
+            "missing 1"
+        );
+        runtime.dispose();
+    }
+
+    #[test]
+    fn interpolates_parameters() {
+        let params = [("min", String::from("3")), ("max", String::from("{min}"))];
+        assert_eq!(interpolate("{min} to {max}", &params[..1]), "3 to {max}");
+        assert_eq!(interpolate("{min}, {min}", &params), "3, 3");
+        assert_eq!(interpolate("{other} {min", &params), "{other} {min");
+        assert_eq!(interpolate("{min}", &[]), "{min}");
+    }
+}
@@ -1,10 +1,11 @@
-use leptos::create_rw_signal;
-use leptos::SignalGet;
-use leptos::{component, view, IntoView, MaybeSignal, RwSignal};
-use leptos::{create_signal, For};
-use leptos::{event_target_value, watch, ReadSignal};
-use leptos::{SignalSet, SignalUpdate};
-use leptos_use::use_css_var;
+use crate::inputs::field::{validate_text, FieldState};
+use crate::inputs::suggestion_list::{Combobox, SuggestionList, DEFAULT_VISIBLE_SUGGESTIONS};
+use crate::inputs::suggestion_source::use_suggestions;
+use crate::{translate, AnyAsyncValidator, AnyValidator, SuggestionSource, ValidationMode};
+use crate::{AddressSuggestion, AnyAddressProvider, Highlight, Matcher};
+use leptos::{component, view, IntoView, MaybeSignal, RwSignal, Signal};
+use leptos::{event_target_value, Callable, Callback};
+use leptos::{SignalGet, SignalSet, SignalUpdate, SignalWith, SignalWithUntracked};
 use uuid::Uuid;
 #[derive(PartialEq)]
 pub enum AddressInputStyle {
@@ -18,12 +19,18 @@ pub fn InputAddress(
     /// The tracked value
     #[prop(into)]
     address: RwSignal<String>,
+    /// The name of the input, used to match the errors returned by a server
+    #[prop(optional, into)]
+    name: Option<String>,
+    /// When the validation result is displayed, defaults to the mode of the form or of the theme
+    #[prop(optional)]
+    mode: Option<ValidationMode>,
     /// The label of the input, defaults to `Address`
-    #[prop(into, default = MaybeSignal::from(String::from("Address")))]
+    #[prop(into, default = MaybeSignal::derive(|| translate("label.address", &[])))]
     label: MaybeSignal<String>,
     /// Whether or not the input is required, defaults to `false`
-    #[prop(default = false)]
-    required: bool,
+    #[prop(into, default = MaybeSignal::from(false))]
+    required: MaybeSignal<bool>,
     /// Whether or not the input is disabled, defaults to `false`
     #[prop(default = MaybeSignal::from(false))]
     disabled: MaybeSignal<bool>,
@@ -33,76 +40,111 @@ pub fn InputAddress(
     /// The suggestions of the input
     #[prop(default = MaybeSignal::from(None))]
     suggestions: MaybeSignal<Option<Vec<String>>>,
+    /// Filters and ranks the static suggestions by the typed text, and highlights the matched
+    /// characters of the suggestions. The static suggestions are displayed as given otherwise.
+    #[prop(optional)]
+    matcher: Option<Matcher>,
+    /// Fetches the suggestions as the user types, replacing the static suggestions
+    #[prop(optional, into)]
+    provider: Option<AnyAddressProvider>,
+    /// Called with the suggestion chosen by the user, with its structured address
+    /// when it comes from the provider
+    #[prop(optional, into)]
+    on_select: Option<Callback<AddressSuggestion>>,
+    /// The number of suggestions visible without scrolling, defaults to 5
+    #[prop(default = DEFAULT_VISIBLE_SUGGESTIONS)]
+    visible_suggestions: usize,
+    /// The validator of the address on change
+    #[prop(optional, into)]
+    validator: Option<AnyValidator>,
+    /// The asynchronous validator of the input value, run once the value
+    /// stopped changing and passed the synchronous validation
+    #[prop(optional, into)]
+    async_validator: Option<AnyAsyncValidator>,
 ) -> impl IntoView {
-    let error_message = create_rw_signal(String::new());
+    let field = FieldState::new(name.clone(), mode, address, move |address| {
+        validate_text(address, required, validator.as_ref())
+    });
 
-    let _ = watch(
-        move || address.get(),
-        move |address, _, _| {
-            if required && address.is_empty() {
-                error_message.update(|v| *v = String::from("This field is required"));
-            }
-        },
-        false,
-    );
-
-    let is_invalid_change = create_rw_signal(false);
-    let is_valid_change = create_rw_signal(false);
+    if let Some(async_validator) = async_validator {
+        field.validate_async(address, async_validator);
+    }
 
     let id = Uuid::new_v4().to_string();
     let id = move || id.clone();
 
-    let label = label.get();
-    let label = move || label.clone();
+    let label = move || label.get();
 
-    let selected = create_rw_signal(0usize);
+    let static_suggestions = move || {
+        let suggestions = suggestions.get().unwrap_or_default().into_iter();
+        suggestions
+            .map(AddressSuggestion::from_label)
+            .collect::<Vec<_>>()
+    };
+    let source = match (provider, matcher) {
+        (Some(provider), _) => SuggestionSource::from(provider),
+        (None, Some(matcher)) => {
+            SuggestionSource::list(Signal::derive(static_suggestions)).matcher(matcher)
+        }
+        // The static suggestions are displayed as given, whatever the text.
+        (None, None) => SuggestionSource::filter(move |_| static_suggestions()),
+    };
+    let display = Callback::new(|suggestion: AddressSuggestion| suggestion.label);
+    let (candidates, loading) = use_suggestions(source, address, display);
+    let combobox = Combobox::new(
+        Signal::derive(move || candidates.with(Vec::len)),
+        visible_suggestions,
+    );
 
-    let (suggestions, set_suggestions) = create_signal(suggestions.get());
+    let choose = Callback::new(move |index: usize| {
+        let Some(suggestion) = candidates.with_untracked(|c| c.get(index).cloned()) else {
+            return;
+        };
+        address.set(suggestion.label.clone());
+        combobox.close();
+        if let Some(on_select) = on_select {
+            on_select.call(suggestion);
+        }
+    });
+    let render = Callback::new(move |suggestion: AddressSuggestion| match matcher {
+        Some(matcher) => {
+            view! { <Highlight text=suggestion.label query=address matcher=matcher/> }.into_view()
+        }
+        None => suggestion.label.into_view(),
+    });
 
     view! {
-        <div class="input-group">
+        <div class="input-group" class:pending=move || field.pending.get() || combobox.open.get() && loading.get()>
             <input
                 type="text"
                 id=id()
+                role="combobox"
+                aria-autocomplete="list"
+                aria-controls=combobox.list_id()
+                aria-expanded=move || combobox.expanded().to_string()
+                aria-activedescendant=move || combobox.active_descendant()
+                name=name
                 class="input"
                 class:outline = style == AddressInputStyle::Outline || style == AddressInputStyle::Rounded
                 class:rounded = style == AddressInputStyle::Rounded
                 class:search = style == AddressInputStyle::Search
                 class:underline = style == AddressInputStyle::Underline
-                class=("valid-input", move || is_valid_change.get())
+                class=("valid-input", move || field.show_valid())
+                class=("invalid-input", move || field.show_invalid())
                 placeholder=" "
                 prop:value=address
-                required=move || if required {Some(true)} else {None}
+                required=move || if required.get() {Some(true)} else {None}
                 disabled=move || disabled
                 on:input=move |e| {
                     let val = event_target_value(&e);
-                    if required && val.is_empty() {
-                        is_invalid_change.set(false);
-                    }
                     address.update(|v| *v = val);
+                    combobox.reopen();
                 }
-                on:keydown=move |e|{
-                    if suggestions.get().is_some() {
-                        let s = suggestions.get().unwrap();
-                        let len = s.len();
-                        if e.key() == "ArrowDown" {
-                            if selected.get() < len - 1 {
-                                selected.update(|v| *v += 1);
-                            }
-                        }
-                        if e.key() == "ArrowUp" {
-                            if selected.get() > 0 {
-                                selected.update(|v| *v -= 1);
-                            }
-                        }
-                        if e.key() == "Enter" {
-                            e.prevent_default();
-                            let suggestion = s.get(selected.get()).unwrap();
-                            address.update(|v| *v = suggestion.clone());
-                            set_suggestions.set(None);
-                        }
-                    }
+                on:focusout=move |_| {
+                    combobox.close();
+                    field.touch()
                 }
+                on:keydown=move |e| combobox.on_keydown(&e, choose)
                 />
             <label
                 for=id()
@@ -113,64 +155,17 @@ pub fn InputAddress(
             >
                 {label}
             </label>
-            <Suggestions value=address suggestions=suggestions selected=selected style=style />
-            <p class="input-error" class=("show-error", move || is_invalid_change.get() )>
-                {error_message}
+            <SuggestionList
+                items=candidates.into()
+                combobox=combobox
+                key=display
+                render=render
+                on_choose=choose
+                rounded=style == AddressInputStyle::Rounded
+            />
+            <p class="input-error" class=("show-error", move || field.show_invalid() )>
+                {move || field.error_message()}
             </p>
         </div>
     }
 }
-
-#[component]
-fn Suggestions(
-    value: RwSignal<String>,
-    suggestions: ReadSignal<Option<Vec<String>>>,
-    selected: RwSignal<usize>,
-    style: AddressInputStyle,
-) -> impl IntoView {
-    view! {
-
-        {move || if suggestions.get().is_some() {
-            let len = suggestions.get().unwrap().len().min(5);
-            let (_list_len, set_list_len) = use_css_var("--list-size");
-            set_list_len.set(len.to_string());
-
-
-            view!{
-                <div class="suggestions"
-                    class:rounded=style == AddressInputStyle::Rounded
-                >
-                <ul>
-                    <For
-                        each=move || suggestions.get().unwrap().into_iter().enumerate()
-                        key=|s| s.clone()
-                        children=move |(i, val)| {
-
-                        let suggestion = val.clone();
-
-                            view!{
-
-                                <li class:selected=move || selected.get() == i>
-                                    <button
-                                        type="button"
-                                        class="suggestion"
-                                        on:click=move |_| {
-                                            value.set(suggestion.clone());
-                                            selected.set(i);
-                                        }
-                                        on:mouseenter=move |_| selected.set(i)
-                                    >
-                                        {val}
-                                    </button>
-                                </li>
-                            }
-                        }
-                    />
-                </ul>
-                </div>
-            }.into_view()
-        }else{
-            view!{}.into_view()
-        }}
-    }
-}
@@ -0,0 +1,253 @@
+use crate::inputs::field::FieldState;
+use crate::ValidationMode;
+use crate::{country_name, translate, Address, AddressField, AddressFormat, AddressInputStyle};
+use leptos::html::Fieldset;
+use leptos::MaybeSignal;
+use leptos::*;
+use uuid::Uuid;
+use wasm_bindgen::JsCast;
+
+/// The value of a part of the address, the street lines being edited one by one.
+fn part_value(address: &Address, field: AddressField, line: usize) -> String {
+    match field {
+        AddressField::StreetLines => address.street_lines.get(line).cloned().unwrap_or_default(),
+        field => address.value(field),
+    }
+}
+
+fn set_part(address: &mut Address, field: AddressField, line: usize, value: String) {
+    match field {
+        AddressField::StreetLines => {
+            if address.street_lines.len() <= line {
+                address.street_lines.resize(line + 1, String::new());
+            }
+            address.street_lines[line] = value;
+        }
+        AddressField::City => address.city = value,
+        AddressField::Region => address.region = value,
+        AddressField::PostalCode => address.postal_code = value,
+        AddressField::Country => address.country = value,
+    }
+}
+
+/// The `autocomplete` attribute of a part, so browsers fill the address in.
+fn autocomplete(field: AddressField, line: usize) -> &'static str {
+    match (field, line) {
+        (AddressField::StreetLines, 0) => "address-line1",
+        (AddressField::StreetLines, 1) => "address-line2",
+        (AddressField::StreetLines, _) => "address-line3",
+        (AddressField::City, _) => "address-level2",
+        (AddressField::Region, _) => "address-level1",
+        (AddressField::PostalCode, _) => "postal-code",
+        (AddressField::Country, _) => "country",
+    }
+}
+
+/// An address input with a field per part of the address.
+///
+/// The fields are laid out and labelled following the format of the selected country, see
+/// [`AddressFormat`]. When the address is not required, it may be left empty, but once any
+/// field is filled the fields required by the country must be too. Postal codes are checked
+/// against the pattern of the country and uppercased once typed.
+#[component]
+pub fn InputAddressStructured(
+    /// The tracked value
+    address: RwSignal<Address>,
+    /// The name of the input, used to match the errors returned by a server
+    #[prop(optional, into)]
+    name: Option<String>,
+    /// When the validation result is displayed, defaults to the mode of the form or of the theme
+    #[prop(optional)]
+    mode: Option<ValidationMode>,
+    /// The label of the input, defaults to `Address`
+    #[prop(into, default = MaybeSignal::derive(|| translate("label.address", &[])))]
+    label: MaybeSignal<String>,
+    /// Whether or not the address is required, defaults to `false`
+    #[prop(into, default = MaybeSignal::from(false))]
+    required: MaybeSignal<bool>,
+    /// Whether or not the input is disabled, defaults to `false`
+    #[prop(default = MaybeSignal::from(false))]
+    disabled: MaybeSignal<bool>,
+    /// The style of the fields
+    #[prop(default = AddressInputStyle::Underline)]
+    style: AddressInputStyle,
+    /// The codes of the countries offered, defaults to the countries with a bundled format
+    #[prop(optional, into)]
+    countries: Option<Vec<String>>,
+    /// The number of street lines, defaults to 2
+    #[prop(default = 2)]
+    street_lines: usize,
+) -> impl IntoView {
+    let field = FieldState::new(name, mode, address, move |address| {
+        address.format().validate(address, required.get())
+    });
+
+    let format = create_memo(move |_| address.with(Address::format));
+    let countries =
+        countries.unwrap_or_else(|| AddressFormat::countries().map(String::from).collect());
+
+    let outline = style == AddressInputStyle::Outline || style == AddressInputStyle::Rounded;
+    let rounded = style == AddressInputStyle::Rounded;
+    let underline = style == AddressInputStyle::Underline || style == AddressInputStyle::Search;
+
+    let part_error = move |part: AddressField| {
+        if !field.checked.get() {
+            return None;
+        }
+        address
+            .with(|a| format.get().validate_field(a, part, required.get()))
+            .err()
+            .map(|e| e.localized())
+    };
+
+    let part = move |part: AddressField, line: usize| {
+        let id = Uuid::new_v4().to_string();
+        let label_id = id.clone();
+        let label = move || match part {
+            AddressField::StreetLines if line > 0 => translate("address.street2", &[]),
+            _ => format.get().label(part),
+        };
+        let error = move || if line == 0 { part_error(part) } else { None };
+        let value = move || address.with(|a| part_value(a, part, line));
+        view! {
+            <div
+                class="input-group address-part"
+                class:address-street=part == AddressField::StreetLines
+            >
+                <input
+                    type="text"
+                    id=id
+                    class="input"
+                    class:outline=outline
+                    class:rounded=rounded
+                    class:underline=underline
+                    class=("valid-input", move || {
+                        field.checked.get() && error().is_none() && !value().trim().is_empty()
+                    })
+                    class=("invalid-input", move || error().is_some())
+                    autocomplete=autocomplete(part, line)
+                    placeholder=" "
+                    prop:value=value
+                    disabled=move || disabled
+                    on:input=move |e| {
+                        let value = event_target_value(&e);
+                        address.update(|a| set_part(a, part, line, value));
+                    }
+                    on:focusout=move |_| {
+                        if part == AddressField::PostalCode {
+                            let code = address.with_untracked(|a| a.postal_code.trim().to_uppercase());
+                            if address.with_untracked(|a| a.postal_code != code) {
+                                address.update(|a| a.postal_code = code);
+                            }
+                        }
+                    }
+                />
+                <label
+                    for=label_id
+                    class="input-label"
+                    class:outline=outline
+                    class:underline=underline
+                >
+                    {label}
+                </label>
+                <p class="input-error" class=("show-error", move || error().is_some())>
+                    {move || error().unwrap_or_default()}
+                </p>
+            </div>
+        }
+    };
+
+    let rows = move || {
+        format
+            .get()
+            .rows()
+            .iter()
+            .map(|row| {
+                let parts = row
+                    .iter()
+                    .flat_map(|field| match field {
+                        AddressField::StreetLines => (0..street_lines.max(1))
+                            .map(|line| (*field, line))
+                            .collect(),
+                        field => vec![(*field, 0)],
+                    })
+                    .map(|(field, line)| part(field, line))
+                    .collect_view();
+                view! { <div class="address-row">{parts}</div> }
+            })
+            .collect_view()
+    };
+
+    let country_id = Uuid::new_v4().to_string();
+    let country_label_id = country_id.clone();
+    let country_error = move || part_error(AddressField::Country);
+    let fieldset = create_node_ref::<Fieldset>();
+
+    view! {
+        <fieldset
+            class="address-structured"
+            node_ref=fieldset
+            on:focusout=move |e| {
+                let focus_stays_inside = e
+                    .related_target()
+                    .and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+                    .zip(fieldset.get_untracked())
+                    .is_some_and(|(target, fieldset)| fieldset.contains(Some(&target)));
+                if !focus_stays_inside {
+                    field.touch();
+                }
+            }
+        >
+            <legend class="address-legend">{move || label.get()}</legend>
+            <div class="address-row">
+                <div class="input-group address-part filled">
+                    <select
+                        id=country_id
+                        class="input address-country"
+                        class:outline=outline
+                        class:rounded=rounded
+                        class:underline=underline
+                        class=("invalid-input", move || country_error().is_some())
+                        autocomplete="country"
+                        disabled=move || disabled
+                        on:change=move |e| {
+                            let country = event_target_value(&e);
+                            address.update(|a| a.country = country);
+                        }
+                    >
+                        <option value="" prop:selected=move || address.with(|a| a.country.is_empty())></option>
+                        {countries
+                            .into_iter()
+                            .map(|code| {
+                                let selected = {
+                                    let code = code.clone();
+                                    move || address.with(|a| a.country.eq_ignore_ascii_case(&code))
+                                };
+                                let name = {
+                                    let code = code.clone();
+                                    move || country_name(&code)
+                                };
+                                view! { <option value=code prop:selected=selected>{name}</option> }
+                            })
+                            .collect_view()}
+                    </select>
+                    <label
+                        for=country_label_id
+                        class="input-label"
+                        class:outline=outline
+                        class:underline=underline
+                    >
+                        {move || format.get().label(AddressField::Country)}
+                    </label>
+                    <p class="input-error" class=("show-error", move || country_error().is_some())>
+                        {move || country_error().unwrap_or_default()}
+                    </p>
+                </div>
+            </div>
+            {rows}
+            <p class="input-error" class=("show-error", move || field.server_error.with(Option::is_some))>
+                {move || field.server_error.get().unwrap_or_default()}
+            </p>
+        </fieldset>
+    }
+}
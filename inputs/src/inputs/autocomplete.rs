@@ -0,0 +1,203 @@
+use crate::inputs::field::FieldState;
+use crate::inputs::suggestion_list::DEFAULT_VISIBLE_SUGGESTIONS;
+use crate::inputs::suggestion_list::{group_suggestions, Combobox, SuggestionList};
+use crate::inputs::suggestion_source::use_suggestions;
+use crate::validation::no_params;
+use crate::{Highlight, SuggestionSource, TextInputStyle, ValidationError, ValidationMode};
+use leptos::MaybeSignal;
+use leptos::*;
+use uuid::Uuid;
+
+/// A text input suggesting items as the user types, like a product, a user or a city picker.
+///
+/// The suggestions come from a [`SuggestionSource`]: a list, a filter closure, or an
+/// asynchronous fetch. Choosing a suggestion with the mouse or the keyboard sets the value and
+/// writes the displayed text of the item in the input. A typed text is turned into an item
+/// by `parse` if it is given, otherwise the value is `None` until a suggestion is chosen,
+/// and the input is invalid.
+#[component]
+pub fn Autocomplete<T>(
+    /// The chosen item, or the item parsed from the typed text
+    value: RwSignal<Option<T>>,
+    /// Where the suggestions come from
+    source: SuggestionSource<T>,
+    /// The text of an item, written in the input once it is chosen and used to filter lists
+    #[prop(into)]
+    display: Callback<T, String>,
+    /// A unique key of an item, defaults to its displayed text
+    #[prop(optional, into)]
+    key: Option<Callback<T, String>>,
+    /// Turns the typed text into an item, so a value can be entered without a suggestion
+    #[prop(optional, into)]
+    parse: Option<Callback<String, Option<T>>>,
+    /// Renders a suggestion, defaults to its displayed text with the typed text highlighted
+    #[prop(optional, into)]
+    render: Option<Callback<T, View>>,
+    /// The group of an item, displayed as a header above the items of the group
+    #[prop(optional, into)]
+    group: Option<Callback<T, String>>,
+    /// Called with the item chosen among the suggestions
+    #[prop(optional, into)]
+    on_select: Option<Callback<T>>,
+    /// The label of the input
+    #[prop(into)]
+    label: MaybeSignal<String>,
+    /// The name of the input, used to match the errors returned by a server
+    #[prop(optional, into)]
+    name: Option<String>,
+    /// When the validation result is displayed, defaults to the mode of the form or of the theme
+    #[prop(optional)]
+    mode: Option<ValidationMode>,
+    /// Whether or not the input is required, defaults to `false`
+    #[prop(into, default = MaybeSignal::from(false))]
+    required: MaybeSignal<bool>,
+    /// Whether or not the input is disabled, defaults to `false`
+    #[prop(default = MaybeSignal::from(false))]
+    disabled: MaybeSignal<bool>,
+    /// The style of the input
+    #[prop(default = TextInputStyle::Underline)]
+    style: TextInputStyle,
+    /// The number of suggestions visible without scrolling, defaults to 5
+    #[prop(default = DEFAULT_VISIBLE_SUGGESTIONS)]
+    visible_suggestions: usize,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + 'static,
+{
+    let id = Uuid::new_v4().to_string();
+    let id = move || id.clone();
+
+    let label = move || label.get();
+    let key = key.unwrap_or(display);
+    let matcher = source.highlighter();
+
+    let text = create_rw_signal(
+        value
+            .with_untracked(|v| v.clone().map(|v| display.call(v)))
+            .unwrap_or_default(),
+    );
+    let typing = store_value(false);
+    // Writes the chosen item in the input, and clears the input when the value is reset. The
+    // unmatched text being typed is kept.
+    let _ = watch(
+        move || value.get(),
+        move |value, _, _| {
+            let current = text.get_untracked();
+            match value {
+                Some(item) => {
+                    let displayed = display.call(item.clone());
+                    if displayed != current {
+                        text.set(displayed);
+                    }
+                }
+                None if !typing.get_value() && !current.is_empty() => text.set(String::new()),
+                None => {}
+            }
+        },
+        false,
+    );
+
+    let field = FieldState::new(name.clone(), mode, value, move |value| {
+        if value.is_some() {
+            return Ok(());
+        }
+        if text.with(|t| t.trim().is_empty()) {
+            if required.get() {
+                return Err(ValidationError::from_key("required", no_params()));
+            }
+            return Ok(());
+        }
+        Err(ValidationError::from_key(
+            "autocomplete.no_match",
+            no_params(),
+        ))
+    });
+
+    let render = render.unwrap_or_else(|| {
+        Callback::new(move |item| {
+            view! { <Highlight text=display.call(item) query=text matcher=matcher/> }.into_view()
+        })
+    });
+
+    let (suggestions, loading) = use_suggestions(source, text, display);
+    let suggestions = match group {
+        Some(group) => create_memo(move |_| group_suggestions(suggestions.get(), group)),
+        None => suggestions,
+    };
+    let combobox = Combobox::new(
+        Signal::derive(move || suggestions.with(Vec::len)),
+        visible_suggestions,
+    );
+
+    let choose = Callback::new(move |index: usize| {
+        let Some(item) = suggestions.with_untracked(|s| s.get(index).cloned()) else {
+            return;
+        };
+        text.set(display.call(item.clone()));
+        value.set(Some(item.clone()));
+        combobox.close();
+        if let Some(on_select) = on_select {
+            on_select.call(item);
+        }
+    });
+
+    view! {
+        <div class="input-group" class:pending=move || field.pending.get() || combobox.open.get() && loading.get()>
+            <input
+                type="text"
+                autocomplete="off"
+                role="combobox"
+                aria-autocomplete="list"
+                aria-controls=combobox.list_id()
+                aria-expanded=move || combobox.expanded().to_string()
+                aria-activedescendant=move || combobox.active_descendant()
+                id=id()
+                name=name
+                class="input"
+                class:outline = style == TextInputStyle::Outline || style == TextInputStyle::Rounded
+                class:rounded = style == TextInputStyle::Rounded
+                class:underline = style == TextInputStyle::Underline
+                class=("valid-input", move || field.show_valid())
+                class=("invalid-input", move || field.show_invalid())
+                placeholder=" "
+                prop:value=text
+                required=move || if required.get() {Some(true)} else {None}
+                disabled=move || disabled
+                on:input=move |e| {
+                    let typed = event_target_value(&e);
+                    let parsed = parse.and_then(|parse| parse.call(typed.clone()));
+                    text.set(typed);
+                    typing.set_value(true);
+                    value.set(parsed);
+                    typing.set_value(false);
+                    combobox.reopen();
+                }
+                on:keydown=move |e| combobox.on_keydown(&e, choose)
+                on:focusout=move |_| {
+                    combobox.close();
+                    field.touch()
+                }
+            />
+            <label
+                for=id()
+                class="input-label"
+                class:outline = style == TextInputStyle::Outline || style == TextInputStyle::Rounded
+                class:underline = style == TextInputStyle::Underline
+            >
+                {label}
+            </label>
+            <SuggestionList
+                items=suggestions.into()
+                combobox=combobox
+                key=key
+                render=render
+                group=group
+                on_choose=choose
+                rounded=style == TextInputStyle::Rounded
+            />
+            <p class="input-error" class=("show-error", move || field.show_invalid() )>
+                {move || field.error_message()}
+            </p>
+        </div>
+    }
+}
@@ -1,5 +1,5 @@
-use crate::{DisplayStrategy, Gap, Line};
-use leptos::{component, view, Children, ChildrenFn, IntoView, MaybeSignal, View};
+use crate::{translate, DisplayStrategy, Gap, Line};
+use leptos::{component, view, IntoView, MaybeSignal, View};
 
 #[derive(PartialEq, Clone, Copy)]
 pub enum ButtonStyle {
@@ -40,7 +40,8 @@ pub enum ButtonAnimation {
 }
 #[component]
 pub fn Button(
-    #[prop(into, default = MaybeSignal::from(String::from("Submit")))] text: MaybeSignal<String>,
+    #[prop(into, default = MaybeSignal::derive(|| translate("button.submit", &[])))]
+    text: MaybeSignal<String>,
     #[prop(default = ButtonStyle::Outline)] style: ButtonStyle,
     #[prop(default = ButtonRoundness::Rounded)] roundness: ButtonRoundness,
     #[prop(default = ButtonColor::Primary)] color: ButtonColor,
@@ -48,6 +49,7 @@ pub fn Button(
     #[prop(default = ButtonAnimation::Push)] animation: ButtonAnimation,
     #[prop(into, default=None)] content_before: Option<View>,
     #[prop(into, default=None)] content_after: Option<View>,
+    #[prop(into, default = MaybeSignal::from(false))] disabled: MaybeSignal<bool>,
 ) -> impl IntoView {
     view! {
         <button class="button"
@@ -68,6 +70,7 @@ pub fn Button(
             class:fill=animation == ButtonAnimation::Fill
             class:push=animation == ButtonAnimation::Push
             class:float=animation == ButtonAnimation::Float
+            disabled=disabled
         >
             <Line justify=DisplayStrategy::Center align=DisplayStrategy::Center gap=Gap::None>
                 {content_before}
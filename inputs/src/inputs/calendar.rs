@@ -0,0 +1,285 @@
+use crate::translate;
+use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
+use icondata::{IoChevronBackOutline, IoChevronForwardOutline};
+use icondata::{IoPlayBackOutline, IoPlayForwardOutline};
+use leptos::ev::KeyboardEvent;
+use leptos::*;
+use leptos_icons::Icon;
+use wasm_bindgen::JsCast;
+
+/// The keys of the names of the days, from Monday.
+const WEEKDAYS: [&str; 7] = ["mon", "tue", "wed", "thu", "fri", "sat", "sun"];
+
+/// The month of a date, displayed as a grid of weeks, following the
+/// [WAI-ARIA date picker dialog pattern](https://www.w3.org/WAI/ARIA/apg/patterns/dialog-modal/examples/datepicker-dialog/).
+///
+/// The focused day moves with the arrow keys, `Home` and `End` move to the start and the end
+/// of the week, `PageUp` and `PageDown` to the previous and the next month, or year with
+/// `Shift`. `Enter` and `Space` choose the focused day, `Escape` closes the calendar.
+#[component]
+pub(crate) fn Calendar(
+    /// The id of the calendar, prefixing the ids of the days
+    id: String,
+    /// The chosen date
+    selected: Signal<Option<NaiveDate>>,
+    /// The day having the focus, whose month is displayed
+    focused: RwSignal<NaiveDate>,
+    /// The day starting the weeks
+    first_day: Weekday,
+    /// Whether a day can be chosen
+    selectable: Callback<NaiveDate, bool>,
+    /// Called with the chosen day
+    on_select: Callback<NaiveDate>,
+    /// Called when the calendar is closed without choosing a day
+    on_close: Callback<()>,
+) -> impl IntoView {
+    let id = store_value(id);
+    let day_id = move |day: NaiveDate| format!("{}-{}", id.get_value(), day.format("%Y-%m-%d"));
+    let title_id = move || format!("{}-title", id.get_value());
+    let today = chrono::Local::now().date_naive();
+
+    let dialog = create_node_ref::<html::Div>();
+    let grid = create_node_ref::<html::Table>();
+    // Moves the focus to the focused day when the calendar opens and as the day moves, but
+    // leaves it on the buttons changing the month.
+    create_effect(move |_| {
+        let day = focused.get();
+        request_animation_frame(move || {
+            let active = document().active_element().map(web_sys::Node::from);
+            let contains = |node: Option<web_sys::Node>| {
+                node.is_some_and(|node| node.contains(active.as_ref()))
+            };
+            let dialog = dialog
+                .get_untracked()
+                .map(|d| web_sys::Node::from((*d).clone()));
+            let grid = grid
+                .get_untracked()
+                .map(|g| web_sys::Node::from((*g).clone()));
+            if contains(dialog) && !contains(grid) {
+                return;
+            }
+            let cell = document().get_element_by_id(&day_id(day));
+            if let Some(cell) = cell.and_then(|cell| cell.dyn_into::<web_sys::HtmlElement>().ok()) {
+                let _ = cell.focus();
+            }
+        });
+    });
+
+    let move_to = move |day: Option<NaiveDate>| {
+        if let Some(day) = day {
+            focused.set(day);
+        }
+    };
+    let shift_months = move |months: i32| {
+        let day = focused.get_untracked();
+        let shifted = if months < 0 {
+            day.checked_sub_months(Months::new(months.unsigned_abs()))
+        } else {
+            day.checked_add_months(Months::new(months as u32))
+        };
+        move_to(shifted);
+    };
+    let choose = move |day: NaiveDate| {
+        if selectable.call(day) {
+            on_select.call(day);
+        }
+    };
+
+    let on_keydown = move |e: KeyboardEvent| {
+        let day = focused.get_untracked();
+        let from_week_start = days_from(day.weekday(), first_day);
+        match e.key().as_str() {
+            "ArrowLeft" => move_to(day.checked_sub_days(Days::new(1))),
+            "ArrowRight" => move_to(day.checked_add_days(Days::new(1))),
+            "ArrowUp" => move_to(day.checked_sub_days(Days::new(7))),
+            "ArrowDown" => move_to(day.checked_add_days(Days::new(7))),
+            "Home" => move_to(day.checked_sub_days(Days::new(from_week_start))),
+            "End" => move_to(day.checked_add_days(Days::new(6 - from_week_start))),
+            "PageUp" => shift_months(if e.shift_key() { -12 } else { -1 }),
+            "PageDown" => shift_months(if e.shift_key() { 12 } else { 1 }),
+            "Enter" | " " => choose(day),
+            "Escape" => on_close.call(()),
+            _ => return,
+        }
+        e.prevent_default();
+    };
+
+    let weeks = move || weeks(focused.get(), first_day);
+    let weekdays = (0..7)
+        .map(|offset| {
+            let weekday = WEEKDAYS[(first_day.num_days_from_monday() as usize + offset) % 7];
+            view! {
+                <th scope="col" abbr=move || translate(&format!("date.weekday.{weekday}"), &[])>
+                    {move || translate(&format!("date.weekday_short.{weekday}"), &[])}
+                </th>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <div
+            id=id.get_value()
+            class="calendar"
+            role="dialog"
+            tabindex="-1"
+            aria-labelledby=title_id()
+            node_ref=dialog
+        >
+            <div class="calendar-header">
+                <button
+                    type="button"
+                    class="calendar-nav"
+                    aria-label=move || translate("date.previous_year", &[])
+                    on:click=move |_| shift_months(-12)
+                >
+                    <Icon icon=IoPlayBackOutline />
+                </button>
+                <button
+                    type="button"
+                    class="calendar-nav"
+                    aria-label=move || translate("date.previous_month", &[])
+                    on:click=move |_| shift_months(-1)
+                >
+                    <Icon icon=IoChevronBackOutline />
+                </button>
+                <h2 id=title_id() class="calendar-title" aria-live="polite">
+                    {move || {
+                        let day = focused.get();
+                        let month = translate(&format!("date.month.{}", day.month()), &[]);
+                        format!("{month} {}", day.year())
+                    }}
+                </h2>
+                <button
+                    type="button"
+                    class="calendar-nav"
+                    aria-label=move || translate("date.next_month", &[])
+                    on:click=move |_| shift_months(1)
+                >
+                    <Icon icon=IoChevronForwardOutline />
+                </button>
+                <button
+                    type="button"
+                    class="calendar-nav"
+                    aria-label=move || translate("date.next_year", &[])
+                    on:click=move |_| shift_months(12)
+                >
+                    <Icon icon=IoPlayForwardOutline />
+                </button>
+            </div>
+            <table class="calendar-grid" role="grid" aria-labelledby=title_id() node_ref=grid on:keydown=on_keydown>
+                <thead>
+                    <tr>{weekdays}</tr>
+                </thead>
+                <tbody>
+                    <For
+                        each=weeks
+                        key=|week| week.iter().flatten().next().copied()
+                        children=move |week| {
+                            week.into_iter()
+                                .map(|day| {
+                                    let Some(day) = day else {
+                                        return view! { <td role="gridcell"></td> };
+                                    };
+                                    let is_focused = move || focused.get() == day;
+                                    let is_selected = move || selected.get() == Some(day);
+                                    let outside = move || focused.get().month() != day.month();
+                                    let disabled = !selectable.call(day);
+                                    view! {
+                                        <td role="gridcell" aria-selected=move || is_selected().to_string()>
+                                            <button
+                                                type="button"
+                                                id=day_id(day)
+                                                class="calendar-day"
+                                                class:selected=is_selected
+                                                class:today=day == today
+                                                class:outside=outside
+                                                class:disabled=disabled
+                                                aria-current=(day == today).then_some("date")
+                                                aria-disabled=disabled.then_some("true")
+                                                tabindex=move || if is_focused() { "0" } else { "-1" }
+                                                on:click=move |_| {
+                                                    focused.set(day);
+                                                    choose(day);
+                                                }
+                                            >
+                                                {day.day()}
+                                            </button>
+                                        </td>
+                                    }
+                                })
+                                .collect_view()
+                        }
+                    />
+                </tbody>
+            </table>
+        </div>
+    }
+}
+
+/// The weeks of the month of a day, as a grid of up to 6 weeks starting on `first_day`.
+///
+/// The days out of the range of [`NaiveDate`] are left empty, and the weeks without any day
+/// are dropped.
+pub(crate) fn weeks(day: NaiveDate, first_day: Weekday) -> Vec<Vec<Option<NaiveDate>>> {
+    let first = day.with_day(1).unwrap_or(day);
+    let offset = days_from(first.weekday(), first_day) as i64;
+    let shift = |days: i64| {
+        if days < 0 {
+            first.checked_sub_days(Days::new(days.unsigned_abs()))
+        } else {
+            first.checked_add_days(Days::new(days as u64))
+        }
+    };
+    (0..6i64)
+        .map(|week| {
+            (0..7i64)
+                .map(|d| shift(week * 7 + d - offset))
+                .collect::<Vec<_>>()
+        })
+        .filter(|week| week.iter().any(Option::is_some))
+        .collect()
+}
+
+/// The number of days from the first day of the week to the day.
+pub(crate) fn days_from(day: Weekday, first_day: Weekday) -> u64 {
+    u64::from((day.num_days_from_monday() + 7 - first_day.num_days_from_monday()) % 7)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weeks_start_on_the_first_day() {
+        let day = NaiveDate::from_ymd_opt(2024, 12, 18).unwrap();
+        let weeks = weeks(day, Weekday::Mon);
+        assert_eq!(weeks.len(), 6);
+        assert_eq!(weeks[0][0], NaiveDate::from_ymd_opt(2024, 11, 25));
+        assert_eq!(weeks[5][6], NaiveDate::from_ymd_opt(2025, 1, 5));
+        let weeks = super::weeks(day, Weekday::Sun);
+        assert_eq!(weeks[0][0], NaiveDate::from_ymd_opt(2024, 12, 1));
+    }
+
+    #[test]
+    fn weeks_stop_at_the_ends_of_the_range() {
+        let max = NaiveDate::parse_from_str("+262142-12-31", "%Y-%m-%d").unwrap();
+        assert_eq!(max, NaiveDate::MAX);
+        let last = weeks(max, Weekday::Mon);
+        let days = last.iter().flatten().flatten().copied().collect::<Vec<_>>();
+        assert_eq!(days.last(), Some(&NaiveDate::MAX));
+        assert!(last.last().unwrap().iter().any(Option::is_none));
+
+        let first = weeks(NaiveDate::MIN, Weekday::Mon);
+        let days = first
+            .iter()
+            .flatten()
+            .flatten()
+            .copied()
+            .collect::<Vec<_>>();
+        assert_eq!(days.first(), Some(&NaiveDate::MIN));
+        for first_day in [Weekday::Mon, Weekday::Sun, Weekday::Sat] {
+            assert!(!weeks(NaiveDate::MIN, first_day).is_empty());
+            assert!(!weeks(NaiveDate::MAX, first_day).is_empty());
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use crate::inputs::calendar::Calendar;
+use crate::inputs::field::FieldState;
+use crate::validation::no_params;
+use crate::{translate, TextInputStyle, ValidationError, ValidationMode};
+use chrono::{NaiveDate, Weekday};
+use icondata::IoCalendarOutline;
+use leptos::MaybeSignal;
+use leptos::*;
+use leptos_icons::Icon;
+use uuid::Uuid;
+use wasm_bindgen::JsCast;
+
+/// A date input, typed in a format or chosen in a calendar.
+///
+/// The value is `None` while the input is empty or its text is not a date in the format,
+/// in which case the input is invalid.
+#[component]
+pub fn InputDate(
+    /// The tracked value
+    value: RwSignal<Option<NaiveDate>>,
+    /// The name of the input, used to match the errors returned by a server
+    #[prop(optional, into)]
+    name: Option<String>,
+    /// When the validation result is displayed, defaults to the mode of the form or of the theme
+    #[prop(optional)]
+    mode: Option<ValidationMode>,
+    /// Whether or not the input is required, defaults to `false`
+    #[prop(into, default = MaybeSignal::from(false))]
+    required: MaybeSignal<bool>,
+    /// Whether or not the input is disabled, defaults to `false`
+    #[prop(default = MaybeSignal::from(false))]
+    disabled: MaybeSignal<bool>,
+    /// The label of the input, defaults to `Date`
+    #[prop(into, default = MaybeSignal::derive(|| translate("label.date", &[])))]
+    label: MaybeSignal<String>,
+    /// The earliest date accepted
+    #[prop(optional)]
+    min: Option<NaiveDate>,
+    /// The latest date accepted
+    #[prop(optional)]
+    max: Option<NaiveDate>,
+    /// Whether a date is unavailable, like the weekends or the holidays
+    #[prop(optional, into)]
+    disabled_dates: Option<Callback<NaiveDate, bool>>,
+    /// The day starting the weeks of the calendar, defaults to Monday
+    #[prop(default = Weekday::Mon)]
+    first_day: Weekday,
+    /// The format of the typed date, as described by [`chrono::format::strftime`],
+    /// defaults to `%Y-%m-%d`
+    #[prop(into, default = String::from("%Y-%m-%d"))]
+    format: String,
+    /// The style of the input
+    #[prop(default = TextInputStyle::Underline)]
+    style: TextInputStyle,
+) -> impl IntoView {
+    let id = Uuid::new_v4().to_string();
+    let id = move || id.clone();
+    let calendar_id = format!("{}-calendar", id());
+
+    let label = move || label.get();
+
+    let format = store_value(format);
+    let display = move |date: NaiveDate| format.with_value(|f| date.format(f).to_string());
+    let parse =
+        move |text: &str| format.with_value(|f| NaiveDate::parse_from_str(text.trim(), f).ok());
+
+    let text = create_rw_signal(value.get_untracked().map(display).unwrap_or_default());
+    let typing = store_value(false);
+    // Formats the value set from outside, and clears the text it does not read as, like after
+    // a reset. The text being typed is kept.
+    create_effect(move |_| {
+        let current = value.get();
+        if typing.get_value() {
+            return;
+        }
+        text.set(current.map(display).unwrap_or_default());
+    });
+
+    let selectable = Callback::new(move |date: NaiveDate| {
+        min.map_or(true, |min| date >= min)
+            && max.map_or(true, |max| date <= max)
+            && !disabled_dates.is_some_and(|disabled| disabled.call(date))
+    });
+
+    let field = FieldState::new(name.clone(), mode, value, move |value| {
+        let Some(date) = *value else {
+            if !text.with(|t| t.trim().is_empty()) {
+                let example = NaiveDate::from_ymd_opt(2024, 12, 31).map(display);
+                let example = example.unwrap_or_default();
+                return Err(ValidationError::from_key(
+                    "date.invalid",
+                    [("example", example)],
+                ));
+            }
+            if required.get() {
+                return Err(ValidationError::from_key("required", no_params()));
+            }
+            return Ok(());
+        };
+        if let Some(min) = min.filter(|min| date < *min) {
+            return Err(ValidationError::from_key(
+                "date.min",
+                [("min", display(min))],
+            ));
+        }
+        if let Some(max) = max.filter(|max| date > *max) {
+            return Err(ValidationError::from_key(
+                "date.max",
+                [("max", display(max))],
+            ));
+        }
+        if !selectable.call(date) {
+            return Err(ValidationError::from_key("date.unavailable", no_params()));
+        }
+        Ok(())
+    });
+
+    let group = create_node_ref::<html::Div>();
+    let toggle = create_node_ref::<html::Button>();
+    let open = create_rw_signal(false);
+    let calendar_day = create_rw_signal(NaiveDate::default());
+
+    let open_calendar = move || {
+        let today = chrono::Local::now().date_naive();
+        let day = value
+            .get_untracked()
+            .or_else(|| parse(&text.get_untracked()))
+            .unwrap_or(today);
+        let day = match (min, max) {
+            (Some(min), _) if day < min => min,
+            (_, Some(max)) if day > max => max,
+            _ => day,
+        };
+        calendar_day.set(day);
+        open.set(true);
+    };
+    let close_calendar = move || {
+        open.set(false);
+        if let Some(toggle) = toggle.get_untracked() {
+            let _ = toggle.focus();
+        }
+    };
+    let on_select = Callback::new(move |date: NaiveDate| {
+        value.set(Some(date));
+        text.set(display(date));
+        close_calendar();
+    });
+
+    view! {
+        <div
+            class="input-group date"
+            class:pending=move || field.pending.get()
+            node_ref=group
+            on:focusout=move |e: ev::FocusEvent| {
+                let target = e.related_target().and_then(|t| t.dyn_into::<web_sys::Node>().ok());
+                let inside = group.get_untracked().is_some_and(|group| group.contains(target.as_ref()));
+                if !inside {
+                    open.set(false);
+                }
+            }
+        >
+            <input
+                type="text"
+                autocomplete="off"
+                id=id()
+                name=name
+                class="input"
+                class:outline = style == TextInputStyle::Outline || style == TextInputStyle::Rounded
+                class:rounded = style == TextInputStyle::Rounded
+                class:underline = style == TextInputStyle::Underline
+                class=("valid-input", move || field.show_valid())
+                class=("invalid-input", move || field.show_invalid())
+                placeholder=" "
+                prop:value=text
+                required=move || if required.get() {Some(true)} else {None}
+                disabled=move || disabled
+                on:input=move |e| {
+                    let typed = event_target_value(&e);
+                    text.set(typed.clone());
+                    typing.set_value(true);
+                    value.set(parse(&typed));
+                    typing.set_value(false);
+                }
+                on:keydown=move |e| {
+                    if e.key() == "ArrowDown" && e.alt_key() {
+                        e.prevent_default();
+                        open_calendar();
+                    } else if e.key() == "Escape" && open.get_untracked() {
+                        e.prevent_default();
+                        open.set(false);
+                    }
+                }
+                on:focusout=move |_| {
+                    if let Some(current) = value.get_untracked() {
+                        text.set(display(current));
+                    }
+                    field.touch()
+                }
+            />
+            <div class="date-actions">
+                <button
+                    type="button"
+                    class="date-action"
+                    node_ref=toggle
+                    aria-haspopup="dialog"
+                    aria-expanded=move || open.get().to_string()
+                    aria-controls=calendar_id.clone()
+                    aria-label=move || translate("date.choose", &[])
+                    title=move || translate("date.choose", &[])
+                    disabled=move || disabled.get()
+                    on:click=move |_| if open.get_untracked() { open.set(false) } else { open_calendar() }
+                >
+                    <Icon icon=IoCalendarOutline />
+                </button>
+            </div>
+            <label
+                for=id()
+                class="input-label"
+                class:outline = style == TextInputStyle::Outline || style == TextInputStyle::Rounded
+                class:underline = style == TextInputStyle::Underline
+            >
+                {label}
+            </label>
+            <Show when=move || open.get()>
+                <Calendar
+                    id=calendar_id.clone()
+                    selected=value.into()
+                    focused=calendar_day
+                    first_day=first_day
+                    selectable=selectable
+                    on_select=on_select
+                    on_close=Callback::new(move |_| close_calendar())
+                />
+            </Show>
+            <p class="input-error" class=("show-error", move || field.show_invalid() )>
+                {move || field.error_message()}
+            </p>
+        </div>
+    }
+}
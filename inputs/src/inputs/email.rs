@@ -1,13 +1,9 @@
+use crate::inputs::field::{validate_text, FieldState};
+use crate::{translate, AnyAsyncValidator, AnyValidator};
+use crate::{EmailPolicy, EmailSuggester, ValidationMode};
 use leptos::MaybeSignal;
 use leptos::*;
 use uuid::Uuid;
-use validator::Validate;
-
-#[derive(Debug, Validate)]
-pub struct EmailInput {
-    #[validate(email)]
-    pub(crate) email: String,
-}
 
 #[derive(PartialEq, Clone, Copy)]
 pub enum EmailInputStyle {
@@ -16,86 +12,122 @@ pub enum EmailInputStyle {
     Rounded,
 }
 
-/// An email input that will validate email based on HTML5 spec RFC 5322
+/// An email input validated by its policy, which follows the HTML5 spec by default
 #[component]
 pub fn InputEmail(
     /// The tracked value
     email: RwSignal<String>,
+    /// The name of the input, used to match the errors returned by a server
+    #[prop(optional, into)]
+    name: Option<String>,
+    /// When the validation result is displayed, defaults to the mode of the form or of the theme
+    #[prop(optional)]
+    mode: Option<ValidationMode>,
     /// Whether or not the input is required, defaults to `false`
-    #[prop(default = false)]
-    required: bool,
+    #[prop(into, default = MaybeSignal::from(false))]
+    required: MaybeSignal<bool>,
     /// Whether or not the input is disabled, defaults to `false`
     #[prop(default = MaybeSignal::from(false))]
     disabled: MaybeSignal<bool>,
     /// The style of the input
     #[prop(default = EmailInputStyle::Underline)]
     style: EmailInputStyle,
+    /// Label for the input, defaults to `Email`
+    #[prop(default = MaybeSignal::derive(|| translate("label.email", &[])))]
+    label: MaybeSignal<String>,
+    /// The syntax, the domains and the normalization of the address, defaults to the HTML5 spec
+    #[prop(optional, into)]
+    policy: Option<EmailPolicy>,
+    /// Additional validation of the email, applied once the address is valid
+    #[prop(optional, into)]
+    validator: Option<AnyValidator>,
+    /// The asynchronous validator of the input value, run once the value
+    /// stopped changing and passed the synchronous validation
+    #[prop(optional, into)]
+    async_validator: Option<AnyAsyncValidator>,
+    /// Suggests a correction beneath the input when the domain looks mistyped, defaults to `true`
+    #[prop(default = true)]
+    suggest_corrections: bool,
+    /// The domains the corrections are based on
+    #[prop(optional, into)]
+    suggester: Option<EmailSuggester>,
 ) -> impl IntoView {
-    let is_invalid_change = create_rw_signal(false);
-    let is_valid_change = create_rw_signal(false);
-
     let id = Uuid::new_v4().to_string();
     let id = move || id.clone();
 
-    let label = move || "Email".to_string();
+    let label = move || label.get();
 
-    let error_message = create_rw_signal(String::new());
+    let policy = policy.unwrap_or_default();
+    let validator = match validator {
+        Some(validator) => AnyValidator::new(policy.clone()).and(validator),
+        None => AnyValidator::new(policy.clone()),
+    };
 
-    let validate_change = move |email: &str| {
-        EmailInput {
-            email: email.to_string(),
+    // The value is normalized once committed: when it changes without the focus, as on blur
+    // or when a suggestion is chosen, and before a form is submitted with `Enter`.
+    let focused = create_rw_signal(false);
+    let policy = store_value(policy);
+    let normalize = move || {
+        let normalized = email.with_untracked(|email| policy.with_value(|p| p.normalize(email)));
+        if email.with_untracked(|email| *email != normalized) {
+            email.set(normalized);
         }
-        .validate()
-        .is_ok()
     };
+    create_render_effect(move |_| {
+        email.track();
+        if !focused.get() {
+            normalize();
+        }
+    });
 
-    let _ = watch(
-        move || email.get(),
-        move |email, _, _| {
-            if required && email.is_empty() {
-                error_message.update(|v| *v = String::from("This field is required"));
-            } else if !validate_change(&email) {
-                error_message.update(|v| *v = String::from("Please enter a valid email address."));
-                is_valid_change.update(|v| *v = false);
-                is_invalid_change.update(|v| *v = true);
-            } else {
-                is_valid_change.update(|v| *v = true);
-                is_invalid_change.update(|v| *v = false);
-            }
-        },
-        false,
-    );
+    let field = FieldState::new(name.clone(), mode, email, move |email| {
+        validate_text(email, required, Some(&validator))
+    });
+
+    if let Some(async_validator) = async_validator {
+        field.validate_async(email, async_validator);
+    }
+
+    let suggester = suggester.unwrap_or_default();
+    let suggestion = create_memo(move |_| {
+        if !suggest_corrections {
+            return None;
+        }
+        email.with(|email| suggester.suggest(email))
+    });
+    let show_suggestion = move || field.touched.get() && suggestion.with(Option::is_some);
 
     view! {
-        <div class="input-group">
+        <div class="input-group" class:pending=move || field.pending.get()>
             <input
                 type="text"
                 autocomplete="email"
                 id=id()
+                name=name
                 class="input"
                 class:outline = style == EmailInputStyle::Outline || style == EmailInputStyle::Rounded
                 class:rounded = style == EmailInputStyle::Rounded
                 class:underline = style == EmailInputStyle::Underline
-                class=("valid-input", move || is_valid_change.get())
-                class=("invalid-input", move || is_invalid_change.get())
+                class=("valid-input", move || field.show_valid())
+                class=("invalid-input", move || field.show_invalid())
                 placeholder=" "
                 prop:value=email
-                required=move || if required {Some(true)} else {None}
+                required=move || if required.get() {Some(true)} else {None}
                 disabled=move || disabled
                 on:input=move |e| {
                     let val = event_target_value(&e);
                     email.update(|v| *v = val);
                 }
-                on:focusout=move |e|{
-                    if required && event_target_value(&e).is_empty() {
-                        error_message.update(|v| *v = String::from("This field is required"));
-                        is_invalid_change.set(true);
-                        is_valid_change.set(false);
-                    }else if !required && event_target_value(&e).is_empty() {
-                        is_invalid_change.set(false);
-                        is_valid_change.set(true);
+                on:keydown=move |e| {
+                    if e.key() == "Enter" {
+                        normalize();
                     }
                 }
+                on:focusin=move |_| focused.set(true)
+                on:focusout=move |_| {
+                    focused.set(false);
+                    field.touch()
+                }
             />
             <label
                 for=id()
@@ -105,9 +137,25 @@ pub fn InputEmail(
             >
                 {label}
             </label>
-            <p class="input-error" class=("show-error", move || is_invalid_change.get() )>
-                {error_message}
+            <p class="input-error" class=("show-error", move || field.show_invalid() )>
+                {move || field.error_message()}
             </p>
+            <Show when=show_suggestion>
+                <button
+                    type="button"
+                    class="input-suggestion"
+                    on:click=move |_| {
+                        if let Some(suggestion) = suggestion.get_untracked() {
+                            email.set(suggestion);
+                        }
+                    }
+                >
+                    {move || {
+                        let suggestion = suggestion.get().unwrap_or_default();
+                        translate("email.did_you_mean", &[("suggestion", suggestion)])
+                    }}
+                </button>
+            </Show>
         </div>
     }
 }
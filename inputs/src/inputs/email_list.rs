@@ -0,0 +1,372 @@
+use crate::inputs::field::FieldState;
+use crate::validation::no_params;
+use crate::ValidationMode;
+use crate::{translate, AnyValidator, EmailInputStyle, EmailPolicy, ValidationError};
+use icondata::IoClose;
+use leptos::MaybeSignal;
+use leptos::*;
+use leptos_icons::Icon;
+use std::collections::HashMap;
+use uuid::Uuid;
+use wasm_bindgen::JsCast;
+
+/// Whether the character may end the address being typed.
+fn is_separator(c: char) -> bool {
+    c == ',' || c == ';' || c.is_whitespace()
+}
+
+/// The byte ranges of the entries of a list, split on the commas, the semicolons and the
+/// new lines outside the quoted names and the brackets of `Name <address>`.
+///
+/// With `spaces`, the whitespace following an `@` also ends an entry, like while typing.
+fn entries(text: &str, spaces: bool) -> Vec<(usize, usize)> {
+    let mut entries = Vec::new();
+    let (mut start, mut quoted, mut bracketed, mut at) = (0, false, false, false);
+    for (index, c) in text.char_indices() {
+        let ends = match c {
+            '"' if !bracketed => {
+                quoted = !quoted;
+                false
+            }
+            '<' if !quoted => {
+                bracketed = true;
+                false
+            }
+            '>' if !quoted => {
+                bracketed = false;
+                false
+            }
+            _ if quoted || bracketed => false,
+            ',' | ';' | '\n' => true,
+            c => spaces && at && c.is_whitespace(),
+        };
+        at |= c == '@';
+        if ends {
+            entries.push((start, index));
+            start = index + c.len_utf8();
+            at = false;
+        }
+    }
+    entries.push((start, text.len()));
+    entries
+}
+
+/// Splits text into addresses, accepting the `Name <address>` entries of email clients.
+fn parse_addresses(text: &str) -> Vec<String> {
+    entries(text, false)
+        .into_iter()
+        .flat_map(|(start, end)| {
+            let entry = &text[start..end];
+            let bracketed = entry
+                .rsplit_once('<')
+                .and_then(|(_, rest)| rest.split_once('>'));
+            match bracketed {
+                Some((address, _)) => vec![address.trim().to_string()],
+                None => entry.split_whitespace().map(String::from).collect(),
+            }
+        })
+        .filter(|address| !address.is_empty())
+        .collect()
+}
+
+/// The length of the addresses completed in the typed text, before the address being typed.
+///
+/// A comma or a semicolon ends an address, and so does a space once an `@` was typed, but
+/// not inside a quoted name or the brackets of `Name <address>`.
+fn completed_len(text: &str) -> Option<usize> {
+    let entries = entries(text, true);
+    let (start, _) = entries.last()?;
+    (entries.len() > 1).then_some(*start)
+}
+
+/// The keys of the addresses, made of the address and its number of previous occurrences,
+/// as a list set from outside may hold duplicates.
+fn keys(emails: &[String]) -> Vec<(String, usize)> {
+    let mut seen = HashMap::<&str, usize>::new();
+    emails
+        .iter()
+        .map(|email| {
+            let count = seen.entry(email).or_default();
+            *count += 1;
+            (email.clone(), *count - 1)
+        })
+        .collect()
+}
+
+/// The index of the address having the key.
+fn position(emails: &[String], (address, occurrence): &(String, usize)) -> Option<usize> {
+    emails
+        .iter()
+        .enumerate()
+        .filter(|(_, email)| *email == address)
+        .nth(*occurrence)
+        .map(|(index, _)| index)
+}
+
+/// An input of several email addresses, displayed as removable chips.
+///
+/// Addresses are added when a comma, a semicolon, `Enter` or a space following an address is
+/// typed, when the input loses the focus, or when a list is pasted. Entries like
+/// `Jane Doe <jane@example.com>` are added as their address. Each address is normalized and
+/// validated by the policy, like in [`InputEmail`](crate::InputEmail), and the invalid ones
+/// are highlighted. An address already in the list, ignoring the case, is not added again.
+#[component]
+pub fn InputEmailList(
+    /// The tracked addresses
+    emails: RwSignal<Vec<String>>,
+    /// The name of the input, used to match the errors returned by a server
+    #[prop(optional, into)]
+    name: Option<String>,
+    /// When the validation result is displayed, defaults to the mode of the form or of the theme
+    #[prop(optional)]
+    mode: Option<ValidationMode>,
+    /// Whether or not at least one address is required, defaults to `false`
+    #[prop(into, default = MaybeSignal::from(false))]
+    required: MaybeSignal<bool>,
+    /// Whether or not the input is disabled, defaults to `false`
+    #[prop(default = MaybeSignal::from(false))]
+    disabled: MaybeSignal<bool>,
+    /// The style of the input
+    #[prop(default = EmailInputStyle::Underline)]
+    style: EmailInputStyle,
+    /// Label for the input, defaults to `Emails`
+    #[prop(default = MaybeSignal::derive(|| translate("label.emails", &[])))]
+    label: MaybeSignal<String>,
+    /// The syntax, the domains and the normalization of each address, defaults to the HTML5 spec
+    #[prop(optional, into)]
+    policy: Option<EmailPolicy>,
+    /// Additional validation of each address, applied once the address follows the policy
+    #[prop(optional, into)]
+    validator: Option<AnyValidator>,
+) -> impl IntoView {
+    let id = Uuid::new_v4().to_string();
+    let id = move || id.clone();
+
+    let label = move || label.get();
+
+    let policy = policy.unwrap_or_default();
+    let validator = match validator {
+        Some(validator) => AnyValidator::new(policy.clone()).and(validator),
+        None => AnyValidator::new(policy.clone()),
+    };
+    let policy = store_value(policy);
+    let validator = store_value(validator);
+
+    let field = FieldState::new(name.clone(), mode, emails, move |emails: &Vec<String>| {
+        if emails.is_empty() && required.get() {
+            return Err(ValidationError::from_key("required", no_params()));
+        }
+        validator.with_value(|validator| {
+            emails
+                .iter()
+                .try_for_each(|email| validator.validate(email))
+        })
+    });
+
+    let draft = create_rw_signal(String::new());
+    let add = move |addresses: Vec<String>| {
+        if addresses.is_empty() {
+            return;
+        }
+        emails.update(|emails| {
+            for address in addresses {
+                let address = policy.with_value(|policy| policy.normalize(address.trim()));
+                if !address.is_empty() && !emails.iter().any(|e| e.eq_ignore_ascii_case(&address)) {
+                    emails.push(address);
+                }
+            }
+        });
+    };
+    let add_draft = move || {
+        let text = draft.get_untracked();
+        draft.set(String::new());
+        add(parse_addresses(&text));
+    };
+    let remove = move |key: &(String, usize)| {
+        emails.update(|emails| {
+            if let Some(index) = position(emails, key) {
+                emails.remove(index);
+            }
+        })
+    };
+
+    let filled = move || emails.with(|e| !e.is_empty()) || draft.with(|d| !d.is_empty());
+
+    view! {
+        <div class="input-group" class:filled=filled>
+            <div
+                class="input email-list"
+                class:outline = style == EmailInputStyle::Outline || style == EmailInputStyle::Rounded
+                class:rounded = style == EmailInputStyle::Rounded
+                class:underline = style == EmailInputStyle::Underline
+                class=("valid-input", move || field.show_valid())
+                class=("invalid-input", move || field.show_invalid())
+            >
+                <For
+                    each=move || emails.with(|emails| keys(emails))
+                    key=|key| key.clone()
+                    children=move |key| {
+                        let email = key.0.clone();
+                        let invalid = {
+                            let email = email.clone();
+                            move || validator.with_value(|v| v.validate(&email).is_err())
+                        };
+                        let remove_label = {
+                            let email = email.clone();
+                            move || translate("email.remove", &[("email", email.clone())])
+                        };
+                        view! {
+                            <span class="email-chip" class:invalid=invalid>
+                                {email}
+                                <button
+                                    type="button"
+                                    class="email-chip-remove"
+                                    aria-label=remove_label
+                                    disabled=move || disabled.get()
+                                    on:click=move |_| remove(&key)
+                                >
+                                    <Icon icon=IoClose />
+                                </button>
+                            </span>
+                        }
+                    }
+                />
+                <input
+                    type="text"
+                    autocomplete="email"
+                    id=id()
+                    name=name
+                    class="email-list-entry"
+                    placeholder=" "
+                    prop:value=draft
+                    disabled=move || disabled
+                    on:input=move |e| {
+                        let value = event_target_value(&e);
+                        match completed_len(&value) {
+                            Some(index) => {
+                                add(parse_addresses(&value[..index]));
+                                draft.set(value[index..].trim_start_matches(is_separator).to_string());
+                            }
+                            None => draft.set(value),
+                        }
+                    }
+                    on:paste=move |e| {
+                        let text = e
+                            .unchecked_ref::<web_sys::ClipboardEvent>()
+                            .clipboard_data()
+                            .and_then(|data| data.get_data("text").ok())
+                            .unwrap_or_default();
+                        if text.contains(is_separator) {
+                            e.prevent_default();
+                            let text = format!("{}{text}", draft.get_untracked());
+                            draft.set(String::new());
+                            add(parse_addresses(&text));
+                        }
+                    }
+                    on:keydown=move |e| match e.key().as_str() {
+                        "Enter" if draft.with_untracked(|d| !d.trim().is_empty()) => {
+                            e.prevent_default();
+                            add_draft();
+                        }
+                        "Backspace"
+                            if draft.with_untracked(String::is_empty)
+                                && emails.with_untracked(|e| !e.is_empty()) =>
+                        {
+                            emails.update(|emails| {
+                                emails.pop();
+                            });
+                        }
+                        _ => {}
+                    }
+                    on:focusout=move |_| {
+                        add_draft();
+                        field.touch()
+                    }
+                />
+            </div>
+            <label
+                for=id()
+                class="input-label"
+                class:outline = style == EmailInputStyle::Outline || style == EmailInputStyle::Rounded
+                class:underline = style == EmailInputStyle::Underline
+            >
+                {label}
+            </label>
+            <p class="input-error" class=("show-error", move || field.show_invalid() )>
+                {move || field.error_message()}
+            </p>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn separators() {
+        for c in [',', ';', ' ', '\n', '\t', '\u{a0}'] {
+            assert!(is_separator(c), "{c:?}");
+        }
+        for c in ['@', '.', '<', '"', 'a'] {
+            assert!(!is_separator(c), "{c:?}");
+        }
+    }
+
+    #[test]
+    fn parses_lists() {
+        assert_eq!(
+            parse_addresses("a@x.com, b@x.com;c@x.com\nd@x.com e@x.com"),
+            ["a@x.com", "b@x.com", "c@x.com", "d@x.com", "e@x.com"]
+        );
+        assert_eq!(parse_addresses(" ,; a@x.com ,, "), ["a@x.com"]);
+        assert!(parse_addresses("").is_empty());
+    }
+
+    #[test]
+    fn parses_display_names() {
+        assert_eq!(
+            parse_addresses("Jane Doe <jane@x.com>, <john@x.com>; Bob <bob@x.com>"),
+            ["jane@x.com", "john@x.com", "bob@x.com"]
+        );
+        assert_eq!(
+            parse_addresses("\"Doe, Jane\" <jane@x.com>, \"Smith; John\" <john@x.com>"),
+            ["jane@x.com", "john@x.com"]
+        );
+        assert_eq!(
+            parse_addresses("\"Doe, Jane <jd>\" <jane@x.com>"),
+            ["jane@x.com"]
+        );
+    }
+
+    #[test]
+    fn typed_display_names_are_not_split() {
+        assert_eq!(completed_len("Jane"), None);
+        assert_eq!(completed_len("Jane Doe "), None);
+        assert_eq!(completed_len("Jane Doe <jane@x"), None);
+        assert_eq!(completed_len("Jane Doe <jane@x.com"), None);
+        assert_eq!(completed_len("\"Doe, Jane\" <jane@x.com"), None);
+        assert_eq!(completed_len("Jane Doe <jane@x.com> "), Some(22));
+        assert_eq!(completed_len("Jane Doe <jane@x.com>,"), Some(22));
+        assert_eq!(completed_len("a@x.com b"), Some(8));
+        assert_eq!(completed_len("a@x.com, b@x"), Some(8));
+        assert_eq!(completed_len("a, b"), Some(2));
+    }
+
+    #[test]
+    fn duplicates_have_distinct_keys() {
+        let emails = ["a@x.com", "b@x.com", "a@x.com"].map(String::from);
+        let keys = keys(&emails);
+        assert_eq!(
+            keys,
+            [
+                (String::from("a@x.com"), 0),
+                (String::from("b@x.com"), 0),
+                (String::from("a@x.com"), 1),
+            ]
+        );
+        assert_eq!(position(&emails, &keys[0]), Some(0));
+        assert_eq!(position(&emails, &keys[2]), Some(2));
+        assert_eq!(position(&emails, &(String::from("a@x.com"), 2)), None);
+    }
+}
@@ -0,0 +1,374 @@
+use crate::validation::no_params;
+use crate::{AnyAsyncValidator, AnyValidator, FormContext, GlobalTheme};
+use crate::{ValidationError, ValidationMode};
+use leptos::{create_local_resource, create_render_effect, create_rw_signal, untrack};
+use leptos::{on_cleanup, store_value, use_context, watch, RwSignal, SignalGet};
+use leptos::{MaybeSignal, SignalGetUntracked, SignalSet, SignalWith, SignalWithUntracked};
+use leptos_use::signal_debounced;
+use web_sys::AbortController;
+
+/// The validation state of an input, shared with the surrounding [`FormContext`].
+#[derive(Clone, Copy)]
+pub(crate) struct FieldState {
+    /// The validation error of the current value, `None` if the value is valid
+    pub(crate) error: RwSignal<Option<ValidationError>>,
+    /// Whether the validation result is displayed on the input
+    pub(crate) checked: RwSignal<bool>,
+    /// Whether the input lost the focus at least once
+    pub(crate) touched: RwSignal<bool>,
+    /// Whether the value differs from the initial value
+    pub(crate) dirty: RwSignal<bool>,
+    /// The error of the asynchronous validation of the current value
+    pub(crate) async_error: RwSignal<Option<ValidationError>>,
+    /// Whether the asynchronous validation of the current value is running
+    pub(crate) pending: RwSignal<bool>,
+    /// The error returned by the server for the current value
+    pub(crate) server_error: RwSignal<Option<String>>,
+    /// When the validation result is displayed
+    pub(crate) mode: ValidationMode,
+}
+
+impl FieldState {
+    /// Tracks the validation state of `value`, and registers the input with the
+    /// surrounding form if there is one.
+    pub(crate) fn new<T>(
+        name: Option<String>,
+        mode: Option<ValidationMode>,
+        value: RwSignal<T>,
+        validate: impl Fn(&T) -> Result<(), ValidationError> + 'static,
+    ) -> Self
+    where
+        T: Clone + PartialEq + 'static,
+    {
+        let form = use_context::<FormContext>();
+        let mode = mode
+            .or_else(|| form.and_then(|f| f.validation_mode()))
+            .or_else(|| {
+                use_context::<RwSignal<GlobalTheme>>()
+                    .map(|theme| theme.with_untracked(|t| t.validation_mode))
+            })
+            .unwrap_or_default();
+        // Without a form, nothing is ever submitted.
+        let mode = match (mode, form) {
+            (ValidationMode::OnSubmit, None) => ValidationMode::OnBlur,
+            (mode, _) => mode,
+        };
+
+        let state = FieldState {
+            error: create_rw_signal(None),
+            checked: create_rw_signal(false),
+            touched: create_rw_signal(false),
+            dirty: create_rw_signal(false),
+            async_error: create_rw_signal(None),
+            pending: create_rw_signal(false),
+            server_error: create_rw_signal(None),
+            mode,
+        };
+
+        // Signals read by the validator are tracked, so rules depending on other
+        // inputs are run again when those inputs change.
+        create_render_effect(move |_| {
+            let error = value.with(|value| validate(value)).err();
+            state.error.set(error);
+        });
+
+        let initial = value.get_untracked();
+        let initial_value = initial.clone();
+        let _ = watch(
+            move || value.get(),
+            move |value, _, _| {
+                state.dirty.set(*value != initial_value);
+                state.server_error.set(None);
+                state.change();
+            },
+            false,
+        );
+
+        if let Some(form) = form {
+            let id = form.register(name, state, move || {
+                value.set(initial.clone());
+                state.reset();
+            });
+            on_cleanup(move || form.unregister(&id));
+        }
+
+        state
+    }
+
+    /// Runs the asynchronous validator once the value stopped changing,
+    /// if the value passed the synchronous validation.
+    ///
+    /// The validation of the previous value is aborted when the next value is validated.
+    pub(crate) fn validate_async(&self, value: RwSignal<String>, validator: AnyAsyncValidator) {
+        let state = *self;
+        let debounced = signal_debounced(value, validator.debounce_delay().as_millis() as f64);
+        let in_flight = store_value(None::<AbortController>);
+
+        let resource = create_local_resource(
+            move || debounced.get(),
+            move |value| {
+                if let Some(previous) = in_flight.try_update_value(Option::take).flatten() {
+                    previous.abort();
+                }
+                let validator = validator.clone();
+                async move {
+                    if value.is_empty() || state.error.get_untracked().is_some() {
+                        return (value, Ok(()));
+                    }
+                    let result = match abort_controller() {
+                        Some(controller) => {
+                            let signal = controller.signal();
+                            in_flight.set_value(Some(controller));
+                            validator.validate_abortable(value.clone(), &signal).await
+                        }
+                        None => validator.validate(value.clone()).await,
+                    };
+                    (value, result)
+                }
+            },
+        );
+
+        let _ = watch(
+            move || (value.get(), state.error.get()),
+            move |(value, error), _, _| match untrack(|| resource.get()) {
+                Some((validated, result)) if validated == *value => {
+                    state.async_error.set(result.err());
+                    state.pending.set(false);
+                }
+                _ => {
+                    state.async_error.set(None);
+                    state.pending.set(!value.is_empty() && error.is_none());
+                }
+            },
+            true,
+        );
+
+        let _ = watch(
+            move || resource.get(),
+            move |result, _, _| {
+                if let Some((validated, result)) = result {
+                    if *validated == value.get_untracked() {
+                        state.async_error.set(result.clone().err());
+                        state.pending.set(false);
+                    }
+                }
+            },
+            false,
+        );
+    }
+
+    /// Updates the display of the validation result after the value changed.
+    fn change(&self) {
+        match self.mode {
+            ValidationMode::OnInput => self.checked.set(true),
+            ValidationMode::OnBlur => self.checked.set(false),
+            ValidationMode::OnSubmit | ValidationMode::EagerAfterTouched => {}
+        }
+    }
+
+    /// Marks the input as touched once it loses the focus.
+    pub(crate) fn touch(&self) {
+        self.touched.set(true);
+        if self.mode != ValidationMode::OnSubmit {
+            self.checked.set(true);
+        }
+    }
+
+    /// Displays the validation result once the form is submitted.
+    pub(crate) fn submit(&self) {
+        self.checked.set(true);
+    }
+
+    pub(crate) fn reset(&self) {
+        self.server_error.set(None);
+        self.checked.set(false);
+        self.touched.set(false);
+        self.dirty.set(false);
+    }
+
+    /// Displays an error returned by the server, until the value changes.
+    pub(crate) fn set_server_error(&self, error: Option<String>) {
+        self.server_error.set(error);
+        self.checked.set(true);
+    }
+
+    /// The synchronous error of the value, its asynchronous error, or the error of the server,
+    /// in the current locale.
+    pub(crate) fn current_error(&self) -> Option<String> {
+        self.error
+            .get()
+            .or_else(|| self.async_error.get())
+            .map(|e| e.localized())
+            .or_else(|| self.server_error.get())
+    }
+
+    pub(crate) fn is_valid(&self) -> bool {
+        self.error.with(|e| e.is_none())
+            && self.async_error.with(|e| e.is_none())
+            && self.server_error.with(|e| e.is_none())
+            && !self.pending.get()
+    }
+
+    pub(crate) fn show_valid(&self) -> bool {
+        self.checked.get() && self.is_valid()
+    }
+
+    pub(crate) fn show_invalid(&self) -> bool {
+        self.checked.get() && self.current_error().is_some()
+    }
+
+    pub(crate) fn error_message(&self) -> String {
+        self.current_error().unwrap_or_default()
+    }
+}
+
+/// Validates a text value. Empty values are only rejected if the input is required,
+/// the validator is applied to the others.
+///
+/// Reads `required`, so it is tracked when called from a validation effect.
+pub(crate) fn validate_text(
+    value: &str,
+    required: MaybeSignal<bool>,
+    validator: Option<&AnyValidator>,
+) -> Result<(), ValidationError> {
+    if value.is_empty() {
+        if required.get() {
+            return Err(ValidationError::from_key("required", no_params()));
+        }
+        return Ok(());
+    }
+    validator.map_or(Ok(()), |v| v.validate(value))
+}
+
+/// A controller aborting an asynchronous validation, only available in the browser.
+fn abort_controller() -> Option<AbortController> {
+    if cfg!(target_arch = "wasm32") {
+        AbortController::new().ok()
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AsyncValidator, ValidationFuture};
+    use leptos::create_runtime;
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+    use std::time::Duration;
+
+    /// An in-memory stand-in for an "is this username taken?" endpoint, recording the
+    /// validated values with the pending state of the field at that time.
+    #[derive(Clone, Default)]
+    struct Usernames {
+        field: Rc<Cell<Option<FieldState>>>,
+        input: Rc<Cell<Option<RwSignal<String>>>>,
+        calls: Rc<RefCell<Vec<(String, bool)>>>,
+        /// The value typed while a value is being validated
+        typed_meanwhile: Rc<RefCell<Option<(String, String)>>>,
+    }
+
+    impl AsyncValidator for Usernames {
+        fn validate(&self, value: String) -> ValidationFuture {
+            let field = self.field.get().expect("the field is attached");
+            let input = self.input.get().expect("the field is attached");
+            self.calls
+                .borrow_mut()
+                .push((value.clone(), field.pending.get_untracked()));
+            let typed = self.typed_meanwhile.borrow().clone();
+            Box::pin(async move {
+                if let Some((_, typed)) = typed.filter(|(validated, _)| *validated == value) {
+                    input.set(typed);
+                }
+                match value.as_str() {
+                    "admin" | "root" => Err(ValidationError::new("This username is taken.")),
+                    _ => Ok(()),
+                }
+            })
+        }
+    }
+
+    /// A username input checked by the in-memory endpoint as soon as it changes.
+    fn username_field(usernames: &Usernames) -> (FieldState, RwSignal<String>) {
+        let value = create_rw_signal(String::new());
+        let field = FieldState::new(
+            None,
+            Some(ValidationMode::OnInput),
+            value,
+            |value| match value.contains(' ') {
+                true => Err(ValidationError::new("No spaces.")),
+                false => Ok(()),
+            },
+        );
+        usernames.field.set(Some(field));
+        usernames.input.set(Some(value));
+        let validator = AnyAsyncValidator::new(usernames.clone()).debounce(Duration::ZERO);
+        field.validate_async(value, validator);
+        (field, value)
+    }
+
+    #[test]
+    fn pending_then_error() {
+        let runtime = create_runtime();
+        let usernames = Usernames::default();
+        let (field, value) = username_field(&usernames);
+
+        value.set("admin".to_string());
+        assert_eq!(*usernames.calls.borrow(), [("admin".to_string(), true)]);
+        assert!(!field.pending.get_untracked());
+        assert_eq!(
+            field.async_error.get_untracked(),
+            Some(ValidationError::new("This username is taken."))
+        );
+        assert!(!field.is_valid());
+
+        value.set("alice".to_string());
+        assert!(!field.pending.get_untracked());
+        assert_eq!(field.async_error.get_untracked(), None);
+        assert!(field.is_valid());
+        runtime.dispose();
+    }
+
+    #[test]
+    fn stale_results_are_discarded() {
+        let runtime = create_runtime();
+        let usernames = Usernames::default();
+        *usernames.typed_meanwhile.borrow_mut() = Some(("admin".into(), "admin2".into()));
+        let (field, value) = username_field(&usernames);
+
+        // The error of `admin` arrives once the value is already `admin2`.
+        value.set("admin".to_string());
+        assert_eq!(value.get_untracked(), "admin2");
+        assert_eq!(field.async_error.get_untracked(), None);
+        runtime.dispose();
+    }
+
+    #[test]
+    fn invalid_and_empty_values_are_not_sent() {
+        let runtime = create_runtime();
+        let usernames = Usernames::default();
+        let (field, value) = username_field(&usernames);
+
+        value.set("ad min".to_string());
+        value.set(String::new());
+        assert!(usernames.calls.borrow().is_empty());
+        assert!(!field.pending.get_untracked());
+        assert_eq!(field.async_error.get_untracked(), None);
+        runtime.dispose();
+    }
+
+    #[test]
+    fn on_submit_without_form_falls_back_to_on_blur() {
+        let runtime = create_runtime();
+        let value = create_rw_signal(String::new());
+        let field = FieldState::new(None, Some(ValidationMode::OnSubmit), value, |_| {
+            Err(ValidationError::new("Invalid."))
+        });
+        assert_eq!(field.mode, ValidationMode::OnBlur);
+
+        field.touch();
+        assert!(field.show_invalid());
+        runtime.dispose();
+    }
+}
@@ -1,5 +1,12 @@
-use leptos::{component, view};
-use leptos::{Children, IntoView, MaybeSignal};
+use crate::inputs::field::FieldState;
+use crate::{translate, FieldErrors, ValidationMode};
+use leptos::{component, provide_context, view, For, Signal, SignalSet};
+use leptos::{
+    create_rw_signal, RwSignal, SignalGet, SignalUpdate, SignalWith, SignalWithUntracked,
+};
+use leptos::{store_value, Children, IntoView, MaybeSignal, StoredValue};
+use std::rc::Rc;
+use uuid::Uuid;
 
 #[derive(PartialEq, Clone, Copy)]
 pub enum FormBoxStyle {
@@ -24,18 +31,178 @@ pub enum Level {
     Dp6,
     Dp7,
 }
+
+#[derive(Clone)]
+struct RegisteredField {
+    id: String,
+    name: Option<String>,
+    state: FieldState,
+    reset: Rc<dyn Fn()>,
+}
+
+/// The state of the inputs rendered inside a [`FormBox`].
+///
+/// Every input registers itself with the closest form context, which exposes the
+/// aggregated state of the form.
+#[derive(Clone, Copy)]
+pub struct FormContext {
+    fields: RwSignal<Vec<RegisteredField>>,
+    /// The errors of the server that do not belong to any input
+    form_errors: RwSignal<Vec<String>>,
+    /// The default validation mode of the inputs of the form
+    validation_mode: StoredValue<Option<ValidationMode>>,
+}
+
+impl FormContext {
+    pub fn new() -> Self {
+        FormContext {
+            fields: create_rw_signal(Vec::new()),
+            form_errors: create_rw_signal(Vec::new()),
+            validation_mode: store_value(None),
+        }
+    }
+
+    /// Sets the default validation mode of the inputs of the form.
+    /// It only applies to the inputs rendered afterward.
+    pub fn set_validation_mode(&self, mode: ValidationMode) {
+        self.validation_mode.set_value(Some(mode));
+    }
+
+    /// The default validation mode of the inputs of the form, if any.
+    pub fn validation_mode(&self) -> Option<ValidationMode> {
+        self.validation_mode.get_value()
+    }
+
+    /// Whether every input of the form holds a valid value.
+    pub fn is_valid(&self) -> Signal<bool> {
+        let fields = self.fields;
+        Signal::derive(move || fields.with(|f| f.iter().all(|f| f.state.is_valid())))
+    }
+
+    /// Whether any input of the form was changed.
+    pub fn is_dirty(&self) -> Signal<bool> {
+        let fields = self.fields;
+        Signal::derive(move || fields.with(|f| f.iter().any(|f| f.state.dirty.get())))
+    }
+
+    /// Whether any input of the form lost the focus at least once.
+    pub fn touched(&self) -> Signal<bool> {
+        let fields = self.fields;
+        Signal::derive(move || fields.with(|f| f.iter().any(|f| f.state.touched.get())))
+    }
+
+    /// The error messages of the invalid inputs, followed by the errors of the
+    /// server that do not belong to any input.
+    pub fn errors(&self) -> Signal<Vec<String>> {
+        let fields = self.fields;
+        let form_errors = self.form_errors;
+        Signal::derive(move || {
+            let mut errors: Vec<String> =
+                fields.with(|f| f.iter().filter_map(|f| f.state.current_error()).collect());
+            errors.extend(form_errors.get());
+            errors
+        })
+    }
+
+    /// The errors of the server that do not belong to any input.
+    pub fn form_errors(&self) -> Signal<Vec<String>> {
+        self.form_errors.into()
+    }
+
+    /// Displays the errors returned by a server on the inputs with the matching `name`,
+    /// one after the other when an input has several.
+    /// The errors of unknown fields are displayed at the bottom of the [`FormBox`].
+    ///
+    /// An error is cleared once the value of its input changes.
+    pub fn set_errors(&self, errors: FieldErrors) {
+        let mut unmatched = Vec::new();
+        self.fields.with_untracked(|fields| {
+            for f in fields {
+                let messages = f.name.as_deref().map(|name| errors.get(name));
+                let error = messages.filter(|m| !m.is_empty()).map(|m| m.join(" "));
+                f.state.set_server_error(error);
+            }
+            for (field, messages) in errors.iter() {
+                if !fields.iter().any(|f| f.name.as_deref() == Some(field)) {
+                    unmatched.extend(messages.iter().cloned());
+                }
+            }
+        });
+        self.form_errors.set(unmatched);
+    }
+
+    /// Removes the errors returned by a server.
+    pub fn clear_errors(&self) {
+        self.set_errors(FieldErrors::new());
+    }
+
+    /// Displays the validation result of every input, and returns whether the form is valid.
+    pub fn submit(&self) -> bool {
+        self.fields.with_untracked(|fields| {
+            fields.iter().for_each(|f| f.state.submit());
+            fields.iter().all(|f| f.state.is_valid())
+        })
+    }
+
+    /// Restores every input to its initial value.
+    pub fn reset(&self) {
+        self.fields
+            .with_untracked(|fields| fields.iter().for_each(|f| (f.reset)()));
+        self.form_errors.set(Vec::new());
+    }
+
+    pub(crate) fn register(
+        &self,
+        name: Option<String>,
+        state: FieldState,
+        reset: impl Fn() + 'static,
+    ) -> String {
+        let id = Uuid::new_v4().to_string();
+        let field = RegisteredField {
+            id: id.clone(),
+            name,
+            state,
+            reset: Rc::new(reset),
+        };
+        self.fields.update(|f| f.push(field));
+        id
+    }
+
+    pub(crate) fn unregister(&self, id: &str) {
+        self.fields.update(|f| f.retain(|f| f.id != id));
+    }
+}
+
+impl Default for FormContext {
+    fn default() -> Self {
+        FormContext::new()
+    }
+}
+
 #[component]
 pub fn FormBox(
     #[prop(default = FormBoxStyle::SimpleLogin)] style: FormBoxStyle,
     #[prop(default = Padding::Medium)] padding: Padding,
-    #[prop(default = MaybeSignal::from(Some(String::from("Login")))
-    )]
+    #[prop(default = MaybeSignal::derive(|| Some(translate("form.login", &[]))))]
     title: MaybeSignal<Option<String>>,
     #[prop(into, optional)] logo_src: Option<MaybeSignal<String>>,
     #[prop(into, optional)] logo_alt: Option<MaybeSignal<String>>,
     #[prop(into, optional)] footer_content: Option<MaybeSignal<String>>,
+    /// The state of the form, provided to the inputs of the form.
+    /// Pass it to read the state of the form from outside of it.
+    #[prop(optional)]
+    context: Option<FormContext>,
+    /// The default validation mode of the inputs of the form
+    #[prop(optional)]
+    validation_mode: Option<ValidationMode>,
     children: Children,
 ) -> impl IntoView {
+    let context = context.unwrap_or_default();
+    if let Some(mode) = validation_mode {
+        context.set_validation_mode(mode);
+    }
+    provide_context(context);
+
     view! {
         <div
             class="form"
@@ -51,6 +218,12 @@ pub fn FormBox(
 
             {children()}
 
+            <For
+                each=move || context.form_errors().get().into_iter().enumerate()
+                key=|error| error.clone()
+                children=|(_, error)| view! { <p class="input-error show-error">{error}</p> }
+            />
+
             <footer class="form-footer">
                 {footer_content}
             </footer>
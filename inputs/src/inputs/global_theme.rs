@@ -1,3 +1,4 @@
+use crate::ValidationMode;
 use codee::string::FromToStringCodec;
 use csscolorparser::Color;
 use leptos::{component, provide_context, use_context, SignalSet, SignalUpdate};
@@ -249,6 +250,8 @@ pub struct GlobalTheme {
     pub radius: Radius,
     /// Breakpoints
     pub breakpoints: BreakPoints,
+    /// The default validation mode of the inputs
+    pub validation_mode: ValidationMode,
 }
 
 impl Default for GlobalTheme {
@@ -315,6 +318,7 @@ impl Default for GlobalTheme {
             spacing: Spacing::default(),
             radius: Radius::default(),
             breakpoints: BreakPoints::default(),
+            validation_mode: ValidationMode::default(),
         }
     }
 }
@@ -0,0 +1,87 @@
+use crate::Matcher;
+use leptos::*;
+
+/// A text with the characters matching the typed text marked, like a suggestion of an
+/// [`Autocomplete`](crate::Autocomplete) rendered by hand.
+#[component]
+pub fn Highlight(
+    /// The displayed text
+    #[prop(into)]
+    text: String,
+    /// The typed text
+    #[prop(into)]
+    query: Signal<String>,
+    /// How the typed text is looked for, defaults to a substring ignoring the case and the accents
+    #[prop(optional)]
+    matcher: Matcher,
+) -> impl IntoView {
+    let text = store_value(text);
+
+    move || {
+        let positions = query
+            .with(|query| text.with_value(|text| matcher.find(query, text)))
+            .map(|found| found.positions)
+            .unwrap_or_default();
+        text.with_value(|text| runs(text, &positions))
+            .into_iter()
+            .map(|(run, matched)| {
+                if matched {
+                    view! { <mark class="match">{run}</mark> }.into_view()
+                } else {
+                    run.into_view()
+                }
+            })
+            .collect_view()
+    }
+}
+
+/// The text split into runs of matched and unmatched characters.
+fn runs(text: &str, positions: &[usize]) -> Vec<(String, bool)> {
+    let mut runs: Vec<(String, bool)> = Vec::new();
+    let mut positions = positions.iter().peekable();
+    for (index, c) in text.chars().enumerate() {
+        let matched = positions.next_if_eq(&&index).is_some();
+        match runs.last_mut() {
+            Some((run, run_matched)) if *run_matched == matched => run.push(c),
+            _ => runs.push((c.to_string(), matched)),
+        }
+    }
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(text: &str, matched: bool) -> (String, bool) {
+        (text.to_string(), matched)
+    }
+
+    #[test]
+    fn merges_adjacent_characters() {
+        assert_eq!(
+            runs("Mountain", &[0, 1, 2]),
+            [run("Mou", true), run("ntain", false)]
+        );
+        assert_eq!(runs("abc", &[0, 1, 2]), [run("abc", true)]);
+        assert_eq!(
+            runs("abcd", &[1, 3]),
+            [
+                run("a", false),
+                run("b", true),
+                run("c", false),
+                run("d", true)
+            ]
+        );
+    }
+
+    #[test]
+    fn positions_are_characters() {
+        assert_eq!(
+            runs("Zürich", &[1, 2]),
+            [run("Z", false), run("ür", true), run("ich", false)]
+        );
+        assert_eq!(runs("abc", &[]), [run("abc", false)]);
+        assert!(runs("", &[0]).is_empty());
+    }
+}
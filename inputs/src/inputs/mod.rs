@@ -1,16 +1,42 @@
 mod address;
+mod address_structured;
+mod autocomplete;
 mod buttons;
+mod calendar;
+mod date;
 mod email;
+mod email_list;
+mod field;
 mod form;
 mod global_theme;
+mod highlight;
 mod links;
+mod number;
 mod password;
+mod password_checklist;
+mod password_confirm;
+mod password_strength;
+mod suggestion_list;
+mod suggestion_rows;
+mod suggestion_source;
 mod text;
 
 pub use address::{AddressInputStyle, InputAddress};
+pub use address_structured::InputAddressStructured;
+pub use autocomplete::Autocomplete;
 pub use buttons::{Button, ButtonAnimation, ButtonColor, ButtonRoundness, ButtonSize, ButtonStyle};
+pub use date::InputDate;
 pub use email::{EmailInputStyle, InputEmail};
-pub use form::{FormBox, FormBoxStyle, Padding};
+pub use email_list::InputEmailList;
+pub use form::{FormBox, FormBoxStyle, FormContext, Level, Padding};
 pub use global_theme::{GlobalTheme, GlobalThemeProvider, Theme, ThemeColor, ThemeToggler};
-pub use password::{InputPassword, PasswordInputStyle, PasswordValidationLevel};
+pub use highlight::Highlight;
+pub use number::InputNumber;
+pub use password::{
+    InputPassword, PasswordAutocomplete, PasswordInputStyle, PasswordValidationLevel,
+};
+pub use password_checklist::PasswordChecklist;
+pub use password_confirm::InputPasswordConfirm;
+pub use password_strength::PasswordStrengthMeter;
+pub use suggestion_source::{SuggestionFilter, SuggestionFuture, SuggestionSource};
 pub use text::{InputText, TextInputStyle};
@@ -0,0 +1,355 @@
+use crate::inputs::field::FieldState;
+use crate::validation::no_params;
+use crate::{translate, I18n, Number, NumberFormat, TextInputStyle};
+use crate::{ValidationError, ValidationMode};
+use icondata::{IoAddOutline, IoRemoveOutline};
+use leptos::MaybeSignal;
+use leptos::*;
+use leptos_icons::Icon;
+use uuid::Uuid;
+
+/// A numeric input, bound to an integer or a float.
+///
+/// The number is displayed in the format of the locale, like `1 234,5` in French, and the
+/// typed text is read back in the same format. The value is `None` while the input is empty
+/// or its text is not a number, in which case the input is invalid.
+#[component]
+pub fn InputNumber<T>(
+    /// The tracked value
+    value: RwSignal<Option<T>>,
+    /// The name of the input, used to match the errors returned by a server
+    #[prop(optional, into)]
+    name: Option<String>,
+    /// When the validation result is displayed, defaults to the mode of the form or of the theme
+    #[prop(optional)]
+    mode: Option<ValidationMode>,
+    /// Whether or not the input is required, defaults to `false`
+    #[prop(into, default = MaybeSignal::from(false))]
+    required: MaybeSignal<bool>,
+    /// Whether or not the input is disabled, defaults to `false`
+    #[prop(default = MaybeSignal::from(false))]
+    disabled: MaybeSignal<bool>,
+    /// The label of the input
+    #[prop(default = MaybeSignal::from(String::new()))]
+    label: MaybeSignal<String>,
+    /// The lowest value accepted
+    #[prop(optional)]
+    min: Option<T>,
+    /// The highest value accepted
+    #[prop(optional)]
+    max: Option<T>,
+    /// The step of the buttons and of the arrow keys, defaults to 1.
+    /// The value must be a multiple of the step, counted from `min` or from zero
+    #[prop(optional)]
+    step: Option<T>,
+    /// The number of decimals displayed and kept, the ones typed by default
+    #[prop(optional)]
+    precision: Option<usize>,
+    /// Whether the mouse wheel steps the value while the input has the focus, defaults to `false`
+    #[prop(optional)]
+    wheel: bool,
+    /// Whether the increment and decrement buttons are displayed, defaults to `true`
+    #[prop(default = true)]
+    spin_buttons: bool,
+    /// How the numbers are written, defaults to the format of the locale
+    #[prop(optional)]
+    format: Option<NumberFormat>,
+    /// The style of the input
+    #[prop(default = TextInputStyle::Underline)]
+    style: TextInputStyle,
+) -> impl IntoView
+where
+    T: Number,
+{
+    let id = Uuid::new_v4().to_string();
+    let id = move || id.clone();
+    let input_id = id();
+
+    let label = move || label.get();
+
+    let i18n = use_context::<I18n>();
+    let number_format = create_memo(move |_| {
+        format.unwrap_or_else(|| {
+            i18n.map(|i18n| NumberFormat::for_locale(&i18n.locale().get()))
+                .unwrap_or_default()
+        })
+    });
+    let display = move |value: Option<T>| {
+        let format = number_format.get_untracked();
+        value
+            .map(|value| format.format(value, precision))
+            .unwrap_or_default()
+    };
+
+    let text = create_rw_signal(display(value.get_untracked()));
+    let focused = create_rw_signal(false);
+    let typing = store_value(false);
+    // Formats the value set from outside, or in another locale, and clears the text it does
+    // not read as, like after a reset. The text being typed is kept.
+    create_effect(move |_| {
+        let current = value.get();
+        let format = number_format.get();
+        if typing.get_value() {
+            return;
+        }
+        text.set(
+            current
+                .map(|v| format.format(v, precision))
+                .unwrap_or_default(),
+        );
+    });
+
+    let field = FieldState::new(name.clone(), mode, value, move |value| {
+        let format = number_format.get();
+        let Some(value) = *value else {
+            if !text.with(|t| t.trim().is_empty()) {
+                return Err(ValidationError::from_key("number.invalid", no_params()));
+            }
+            if required.get() {
+                return Err(ValidationError::from_key("required", no_params()));
+            }
+            return Ok(());
+        };
+        if let Some(min) = min.filter(|min| value < *min) {
+            let min = format.format(min, None);
+            return Err(ValidationError::from_key("number.min", [("min", min)]));
+        }
+        if let Some(max) = max.filter(|max| value > *max) {
+            let max = format.format(max, None);
+            return Err(ValidationError::from_key("number.max", [("max", max)]));
+        }
+        if let Some(step) = step.filter(|step| !is_step(value, min.unwrap_or(T::ZERO), *step)) {
+            let step = format.format(step, None);
+            return Err(ValidationError::from_key("number.step", [("step", step)]));
+        }
+        Ok(())
+    });
+
+    let clamp = move |value: T| match (min, max) {
+        (Some(min), _) if value < min => min,
+        (_, Some(max)) if value > max => max,
+        _ => value,
+    };
+    let set = move |next: T| {
+        value.set(Some(next));
+        text.set(display(Some(next)));
+    };
+    // Adds the step the number of times, or subtracts it when the number is negative.
+    let step_by = move |times: i32| {
+        if disabled.get_untracked() {
+            return;
+        }
+        let step = step.unwrap_or(T::ONE);
+        let base = min.unwrap_or(T::ZERO);
+        let mut next = value.get_untracked().unwrap_or(T::ZERO);
+        let mut remaining = times.unsigned_abs();
+        // A value between two steps moves to the next one, as in `<input type="number">`.
+        if remaining > 0 && !is_step(next, base, step) {
+            next = snap(next, base, step, times > 0);
+            remaining -= 1;
+        }
+        for _ in 0..remaining {
+            let stepped = if times > 0 {
+                next.checked_add(step)
+            } else {
+                next.checked_sub(step)
+            };
+            match stepped {
+                Some(stepped) => next = stepped,
+                None => break,
+            }
+        }
+        let decimals = precision.unwrap_or_else(|| decimals(step).max(decimals(base)));
+        set(clamp(next.round_to(decimals)));
+    };
+
+    let on_keydown = move |e: ev::KeyboardEvent| {
+        let times = match e.key().as_str() {
+            "ArrowUp" => 1,
+            "ArrowDown" => -1,
+            "PageUp" => 10,
+            "PageDown" => -10,
+            "Home" if min.is_some() => {
+                e.prevent_default();
+                set(min.unwrap_or(T::ZERO));
+                return;
+            }
+            "End" if max.is_some() => {
+                e.prevent_default();
+                set(max.unwrap_or(T::ZERO));
+                return;
+            }
+            _ => return,
+        };
+        e.prevent_default();
+        step_by(times);
+    };
+
+    let at_min = move || {
+        value
+            .get()
+            .zip(min)
+            .is_some_and(|(value, min)| value <= min)
+    };
+    let at_max = move || {
+        value
+            .get()
+            .zip(max)
+            .is_some_and(|(value, max)| value >= max)
+    };
+
+    view! {
+        <div class="input-group number" class:with-spin-buttons=spin_buttons class:pending=move || field.pending.get()>
+            <input
+                type="text"
+                inputmode=if T::INTEGER { "numeric" } else { "decimal" }
+                autocomplete="off"
+                role="spinbutton"
+                aria-valuenow=move || value.get().map(|v| v.to_string())
+                aria-valuemin=min.map(|v| v.to_string())
+                aria-valuemax=max.map(|v| v.to_string())
+                aria-valuetext=text
+                id=id()
+                name=name
+                class="input"
+                class:outline = style == TextInputStyle::Outline || style == TextInputStyle::Rounded
+                class:rounded = style == TextInputStyle::Rounded
+                class:underline = style == TextInputStyle::Underline
+                class=("valid-input", move || field.show_valid())
+                class=("invalid-input", move || field.show_invalid())
+                placeholder=" "
+                prop:value=text
+                required=move || if required.get() {Some(true)} else {None}
+                disabled=move || disabled
+                on:input=move |e| {
+                    let typed = event_target_value(&e);
+                    let parsed = number_format.get_untracked().parse::<T>(&typed);
+                    text.set(typed);
+                    typing.set_value(true);
+                    value.set(parsed);
+                    typing.set_value(false);
+                }
+                on:keydown=on_keydown
+                on:wheel:undelegated=move |e: ev::WheelEvent| {
+                    if !wheel || !focused.get_untracked() || e.delta_y() == 0.0 {
+                        return;
+                    }
+                    e.prevent_default();
+                    step_by(if e.delta_y() < 0.0 { 1 } else { -1 });
+                }
+                on:focusin=move |_| focused.set(true)
+                on:focusout=move |_| {
+                    focused.set(false);
+                    if let Some(current) = value.get_untracked() {
+                        set(precision.map_or(current, |precision| current.round_to(precision)));
+                    }
+                    field.touch()
+                }
+            />
+            <Show when=move || spin_buttons>
+                <div class="number-actions">
+                    <button
+                        type="button"
+                        class="number-action"
+                        tabindex="-1"
+                        aria-controls=input_id.clone()
+                        aria-label=move || translate("number.decrement", &[])
+                        disabled=move || disabled.get() || at_min()
+                        on:mousedown=|e| e.prevent_default()
+                        on:click=move |_| step_by(-1)
+                    >
+                        <Icon icon=IoRemoveOutline />
+                    </button>
+                    <button
+                        type="button"
+                        class="number-action"
+                        tabindex="-1"
+                        aria-controls=input_id.clone()
+                        aria-label=move || translate("number.increment", &[])
+                        disabled=move || disabled.get() || at_max()
+                        on:mousedown=|e| e.prevent_default()
+                        on:click=move |_| step_by(1)
+                    >
+                        <Icon icon=IoAddOutline />
+                    </button>
+                </div>
+            </Show>
+            <label
+                for=id()
+                class="input-label"
+                class:outline = style == TextInputStyle::Outline || style == TextInputStyle::Rounded
+                class:underline = style == TextInputStyle::Underline
+            >
+                {label}
+            </label>
+            <p class="input-error" class=("show-error", move || field.show_invalid() )>
+                {move || field.error_message()}
+            </p>
+        </div>
+    }
+}
+
+/// Whether the value is a whole number of steps away from the base, within the precision
+/// of the type.
+fn is_step<T: Number>(value: T, base: T, step: T) -> bool {
+    let (value, base, step) = (value.to_f64(), base.to_f64(), step.to_f64());
+    if step == 0.0 {
+        return true;
+    }
+    let steps = (value - base) / step;
+    let magnitude = ((value.abs() + base.abs()) / step.abs()).max(1.0);
+    (steps - steps.round()).abs() <= 4.0 * T::EPSILON * magnitude
+}
+
+/// The step after the value, or before it when going down.
+fn snap<T: Number>(value: T, base: T, step: T, up: bool) -> T {
+    let steps = (value.to_f64() - base.to_f64()) / step.to_f64();
+    let steps = if up { steps.ceil() } else { steps.floor() };
+    T::from_f64(base.to_f64() + steps * step.to_f64())
+}
+
+/// The number of decimals written in the number.
+fn decimals<T: Number>(number: T) -> usize {
+    let written = number.to_string();
+    written
+        .split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steps_within_the_precision_of_the_type() {
+        for value in [0.3f32, 0.7, 1.5, 2.2, 100.1] {
+            assert!(is_step(value, 0.0, 0.1), "{value}");
+        }
+        for value in [0.3f64, 0.7, 1.5, 2.2, 100.1] {
+            assert!(is_step(value, 0.0, 0.1), "{value}");
+        }
+        assert!(is_step(0.3f32.round_to(1), 0.0, 0.1));
+        assert!(is_step(1.75f64, 0.25, 0.5));
+        assert!(!is_step(0.35f32, 0.0, 0.1));
+        assert!(!is_step(1.3f64, 0.0, 0.5));
+        assert!(is_step(12u32, 2, 5) && !is_step(13u32, 2, 5));
+        assert!(is_step(3.7f64, 0.0, 0.0));
+    }
+
+    #[test]
+    fn snaps_to_the_next_step() {
+        assert_eq!(snap(1.3f64, 0.0, 0.5, true), 1.5);
+        assert_eq!(snap(1.3f64, 0.0, 0.5, false), 1.0);
+        assert_eq!(snap(1.3f64, 0.25, 0.5, true), 1.75);
+        assert_eq!(snap(7i32, 0, 5, true), 10);
+        assert_eq!(snap(7i32, 0, 5, false), 5);
+        assert_eq!(snap(-7i32, 0, 5, false), -10);
+    }
+
+    #[test]
+    fn counts_decimals() {
+        assert_eq!(decimals(0.05f64), 2);
+        assert_eq!(decimals(1.0f32), 0);
+        assert_eq!(decimals(10u8), 0);
+    }
+}
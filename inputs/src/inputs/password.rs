@@ -1,5 +1,20 @@
+use crate::inputs::field::{validate_text, FieldState};
+use crate::inputs::password_checklist::PasswordChecklist;
+use crate::inputs::password_strength::PasswordStrengthMeter;
+use crate::validation::no_params;
+use crate::validation::SPECIAL_CHARACTERS;
+use crate::{
+    estimate_strength, translate, AnyAsyncValidator, AnyValidator, PasswordStrength,
+    StrengthEstimate,
+};
+use crate::{
+    PasswordGenerator, PasswordPolicy, RequirementCheck, ValidationError, ValidationMode, Validator,
+};
+use icondata::{IoCheckmarkOutline, IoCopyOutline, IoEyeOffOutline, IoEyeOutline, IoKeyOutline};
 use leptos::MaybeSignal;
 use leptos::*;
+use leptos_icons::Icon;
+use leptos_use::{use_clipboard, UseClipboardReturn};
 use uuid::Uuid;
 
 #[derive(PartialEq, Clone, Copy)]
@@ -9,6 +24,28 @@ pub enum PasswordInputStyle {
     Rounded,
 }
 
+/// The value of the `autocomplete` attribute, telling password managers what to fill in
+#[derive(PartialEq, Clone, Copy, Default)]
+pub enum PasswordAutocomplete {
+    /// The password of an existing account, on a login form
+    #[default]
+    CurrentPassword,
+    /// A password being chosen, on a signup or change password form
+    NewPassword,
+    /// Password managers should not fill in the input
+    Off,
+}
+
+impl PasswordAutocomplete {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PasswordAutocomplete::CurrentPassword => "current-password",
+            PasswordAutocomplete::NewPassword => "new-password",
+            PasswordAutocomplete::Off => "off",
+        }
+    }
+}
+
 /// The level of validation for the password
 #[derive(Clone)]
 pub enum PasswordValidationLevel {
@@ -20,83 +57,65 @@ pub enum PasswordValidationLevel {
     Strong,
     /// Custom validation. Contains a function that returns a boolean, or the error message.
     Custom(fn(&str) -> bool, String),
+    /// The password must be estimated at least as strong as the given strength.
+    MinStrength(PasswordStrength),
 }
 
 impl PasswordValidationLevel {
-    const SPECIAL_CHARACTERS: [&'static str; 23] = [
-        "!", "'", "\"", "@", "#", "$", "%", "-", "_", "~", "+", "=", "?", ",", ".", "/", ":", ";",
-        "`", "&", "^", "|", "*",
-    ];
-    fn validate(&self, val: &str) -> bool {
+    fn is_valid(&self, val: &str) -> bool {
         match self {
-            PasswordValidationLevel::Weak => Self::password_is_week(val, 8),
-            PasswordValidationLevel::Medium => Self::password_is_medium(val, 8),
-            PasswordValidationLevel::Strong => Self::password_is_strong(val, 12),
             PasswordValidationLevel::Custom(func, _) => func(val),
+            PasswordValidationLevel::MinStrength(strength) => strength.validate(val).is_ok(),
+            level => level
+                .policy()
+                .map_or(true, |policy| policy.failed(val).is_empty()),
         }
     }
 
-    fn display_message(&self) -> String {
+    fn error(&self) -> ValidationError {
         match self {
-            PasswordValidationLevel::Weak => String::from("The password must be at least 8 characters long, and contain at least one lowercase letter, one uppercase letter."),
-            PasswordValidationLevel::Medium => String::from("The password must be at least 8 characters long, and contain at least one lowercase letter, one uppercase letter, one number and one special character [ !\"'@#$%^&-_=+/?.,:;`&^|* ]."),
-            PasswordValidationLevel::Strong => String::from("The password must be at least 12 characters long, and contain at least one lowercase letter, one uppercase letter, one number and one special character [ !\"'@#$%^&-_=+/?.,:;`&^|* ]."),
-            PasswordValidationLevel::Custom(_, message) => message.clone(),
-        }
-    }
-
-    fn password_is_week(val: &str, len: usize) -> bool {
-        if !Self::is_minmal_len_or_more(val, len) {
-            return false;
-        } else if !Self::contains_lowercase(val) {
-            return false;
-        } else if !Self::contains_uppercase(val) {
-            return false;
+            PasswordValidationLevel::Weak => {
+                ValidationError::from_key("password.weak", [("min", String::from("8"))])
+            }
+            PasswordValidationLevel::Medium => ValidationError::from_key(
+                "password.medium",
+                [
+                    ("min", String::from("8")),
+                    ("special", SPECIAL_CHARACTERS.to_string()),
+                ],
+            ),
+            PasswordValidationLevel::Strong => ValidationError::from_key(
+                "password.strong",
+                [
+                    ("min", String::from("12")),
+                    ("special", SPECIAL_CHARACTERS.to_string()),
+                ],
+            ),
+            PasswordValidationLevel::Custom(_, message) => ValidationError::new(message.clone()),
+            PasswordValidationLevel::MinStrength(_) => {
+                ValidationError::from_key("password.too_guessable", no_params())
+            }
         }
-
-        true
     }
 
-    fn password_is_medium(val: &str, len: usize) -> bool {
-        if !Self::password_is_week(val, len) {
-            return false;
-        } else if !Self::contains_number(val) {
-            return false;
-        } else if !Self::contains_special_character(val) {
-            return false;
+    /// The policy of the level, `None` for a custom validation or a minimum strength.
+    pub fn policy(&self) -> Option<PasswordPolicy> {
+        let policy = PasswordPolicy::new().lowercase(1).uppercase(1);
+        match self {
+            PasswordValidationLevel::Weak => Some(policy.min_length(8)),
+            PasswordValidationLevel::Medium => Some(policy.min_length(8).numbers(1).special(1)),
+            PasswordValidationLevel::Strong => Some(policy.min_length(12).numbers(1).special(1)),
+            PasswordValidationLevel::Custom(_, _) | PasswordValidationLevel::MinStrength(_) => None,
         }
-
-        true
     }
+}
 
-    fn password_is_strong(val: &str, len: usize) -> bool {
-        if !Self::password_is_medium(val, len) {
-            return false;
-        }
-
-        true
-    }
-    fn is_minmal_len_or_more(val: &str, len: usize) -> bool {
-        if val.len() < len {
-            return false;
+impl Validator for PasswordValidationLevel {
+    fn validate(&self, value: &str) -> Result<(), ValidationError> {
+        if !self.is_valid(value) {
+            return Err(self.error());
         }
-        true
-    }
-
-    fn contains_lowercase(val: &str) -> bool {
-        val.chars().any(|c| c.is_lowercase())
-    }
-
-    fn contains_uppercase(val: &str) -> bool {
-        val.chars().any(|c| c.is_uppercase())
-    }
-
-    fn contains_number(val: &str) -> bool {
-        val.chars().any(|c| c.is_numeric())
-    }
-
-    fn contains_special_character(val: &str) -> bool {
-        Self::SPECIAL_CHARACTERS.iter().any(|c| val.contains(c))
+        Ok(())
     }
 }
 
@@ -105,9 +124,15 @@ impl PasswordValidationLevel {
 pub fn InputPassword(
     /// The tracked value
     password: RwSignal<String>,
+    /// The name of the input, used to match the errors returned by a server
+    #[prop(optional, into)]
+    name: Option<String>,
+    /// When the validation result is displayed, defaults to the mode of the form or of the theme
+    #[prop(optional)]
+    mode: Option<ValidationMode>,
     /// Whether or not the input is required, defaults to `true`
-    #[prop(default = true)]
-    required: bool,
+    #[prop(into, default = MaybeSignal::from(true))]
+    required: MaybeSignal<bool>,
     /// Whether or not the input is disabled, defaults to `false`
     #[prop(default = MaybeSignal::from(false))]
     disabled: MaybeSignal<bool>,
@@ -118,69 +143,251 @@ pub fn InputPassword(
     #[prop(default = PasswordValidationLevel::Strong)]
     validate_change: PasswordValidationLevel,
     /// Label for the input, defaults to `Password`
-    #[prop(default = MaybeSignal::from(String::from("Password")))]
+    #[prop(default = MaybeSignal::derive(|| translate("label.password", &[])))]
     label: MaybeSignal<String>,
+    /// Additional validation of the password, applied after the validation level
+    #[prop(optional, into)]
+    validator: Option<AnyValidator>,
+    /// The asynchronous validator of the password, run once the value stopped changing
+    /// and passed the synchronous validation, like [`not_breached`](crate::not_breached)
+    #[prop(optional, into)]
+    async_validator: Option<AnyAsyncValidator>,
+    /// Displays the requirements of the password as a checklist instead of a single error message
+    #[prop(optional)]
+    checklist: bool,
+    /// The rules of the password, replacing the validation level when set
+    #[prop(optional, into)]
+    policy: Option<PasswordPolicy>,
+    /// The result of each requirement for the current password, updated on each stroke
+    #[prop(optional, into)]
+    checks: RwSignal<Vec<RequirementCheck>>,
+    /// Displays a bar showing how hard the password is to guess
+    #[prop(optional)]
+    strength_meter: bool,
+    /// The estimated strength of the current password, updated on each stroke
+    #[prop(optional, into)]
+    strength: Option<RwSignal<StrengthEstimate>>,
+    /// What password managers should fill in, defaults to the current password
+    #[prop(optional)]
+    autocomplete: PasswordAutocomplete,
+    /// Displays a button revealing the password, defaults to `true`
+    #[prop(default = true)]
+    reveal: bool,
+    /// Warns the user when caps lock is on while typing, defaults to `true`
+    #[prop(default = true)]
+    caps_lock_warning: bool,
+    /// Whether a password can be pasted in the input, defaults to `true`
+    #[prop(default = true)]
+    allow_paste: bool,
+    /// Whether the password can be copied or cut from the input once revealed, defaults to `true`
+    #[prop(default = true)]
+    allow_copy: bool,
+    /// Displays a button generating a password that satisfies the policy or the validation level,
+    /// and a button copying it
+    #[prop(optional)]
+    generate: bool,
+    /// The generator of the generate button, defaults to one following the policy or the validation level
+    #[prop(optional, into)]
+    generator: Option<PasswordGenerator>,
+    /// Reveals the password once generated, defaults to `true`
+    #[prop(default = true)]
+    reveal_generated: bool,
 ) -> impl IntoView {
-    let is_invalid_change = create_rw_signal(false);
-    let is_valid_change = create_rw_signal(false);
-
     let id = Uuid::new_v4().to_string();
     let id = move || id.clone();
 
-    let label = label.get();
-    let label = move || label.clone();
-
-    let error_message = create_rw_signal(String::new());
-
-    let validator = validate_change.clone();
-    let _ = watch(
-        move || (password.get()),
-        move |password, _, _| {
-            if required && password.is_empty() {
-                error_message.update(|v| *v = String::from("This field is required"));
-            } else if !validator.validate(&password) {
-                error_message.update(|v| *v = validator.display_message());
-                is_valid_change.update(|v| *v = false);
-                is_invalid_change.update(|v| *v = true);
-            } else {
-                is_valid_change.update(|v| *v = true);
-                is_invalid_change.update(|v| *v = false);
+    let label = move || label.get();
+
+    // A single estimation per stroke, shared by the meter, the parent and a minimum strength.
+    let min_strength = match validate_change {
+        PasswordValidationLevel::MinStrength(min) if policy.is_none() => Some(min),
+        _ => None,
+    };
+    let estimate = (strength_meter || strength.is_some() || min_strength.is_some())
+        .then(|| create_memo(move |_| password.with(|password| estimate_strength(password))));
+
+    let (level, policy) = match (policy, min_strength.zip(estimate)) {
+        (Some(policy), _) => (AnyValidator::new(policy.clone()), Some(policy)),
+        // Validates the value of the input, so the estimation of the memo is reused.
+        (None, Some((min, estimate))) => {
+            let error = validate_change.error();
+            let level = move |_: &str| match estimate.with(|e| e.strength) < min {
+                true => Err(error.clone()),
+                false => Ok(()),
+            };
+            (AnyValidator::from(level), None)
+        }
+        (None, None) => (
+            AnyValidator::new(validate_change.clone()),
+            validate_change.policy(),
+        ),
+    };
+    let validator = match validator {
+        Some(validator) => level.and(validator),
+        None => level,
+    };
+
+    let generator = match generator {
+        Some(generator) => Some(generator),
+        None if generate => Some(match &policy {
+            Some(policy) => PasswordGenerator::for_policy(policy.clone()),
+            None => PasswordGenerator::new(),
+        }),
+        None => None,
+    };
+
+    let field = FieldState::new(name.clone(), mode, password, move |password| {
+        validate_text(password, required, Some(&validator))
+    });
+
+    if let Some(async_validator) = async_validator {
+        field.validate_async(password, async_validator);
+    }
+
+    create_render_effect(move |_| {
+        let results = match &policy {
+            Some(policy) => password.with(|password| policy.check(password)),
+            None => Vec::new(),
+        };
+        checks.set(results);
+    });
+    // The estimation runs on each stroke, and only when the meter, the parent or the
+    // validation level needs it.
+    if let Some((strength, estimate)) = strength.zip(estimate) {
+        create_render_effect(move |_| strength.set(estimate.get()));
+    }
+    // The checklist already tells which requirement is missing.
+    let error_message = move || {
+        if checklist && checks.with(|checks| checks.iter().any(|check| !check.met)) {
+            return String::new();
+        }
+        field.error_message()
+    };
+
+    let input_id = id();
+    let visible = create_rw_signal(false);
+    let caps_lock = create_rw_signal(false);
+    let detect_caps_lock = move |e: ev::KeyboardEvent| {
+        if caps_lock_warning {
+            caps_lock.set(e.get_modifier_state("CapsLock"));
+        }
+    };
+    let block_clipboard = move |allowed: bool, e: ev::Event| {
+        if !allowed {
+            e.prevent_default();
+        }
+    };
+
+    let generated = create_rw_signal(false);
+    let has_generator = generator.is_some();
+    let generate_password = move |_| {
+        if let Some(generator) = &generator {
+            password.set(generator.generate());
+            generated.set(true);
+            if reveal_generated {
+                visible.set(true);
             }
-        },
-        false,
-    );
+        }
+    };
+    let UseClipboardReturn {
+        is_supported,
+        copy,
+        copied,
+        ..
+    } = use_clipboard();
+    let actions = usize::from(reveal) + 2 * usize::from(has_generator);
 
     view! {
-        <div class="input-group">
+        <div
+            class="input-group"
+            class:with-actions={actions > 0}
+            class:pending=move || field.pending.get()
+            style=format!("--password-actions: {actions}")
+        >
             <input
-                type="password"
-                autocomplete="password"
+                type=move || if visible.get() { "text" } else { "password" }
+                autocomplete=autocomplete.as_str()
                 id=id()
+                name=name
                 class="input"
                 class:outline = style == PasswordInputStyle::Outline || style == PasswordInputStyle::Rounded
                 class:rounded = style == PasswordInputStyle::Rounded
                 class:underline = style == PasswordInputStyle::Underline
-                class=("valid-input", move || is_valid_change.get())
-                class=("invalid-input", move || is_invalid_change.get())
+                class=("valid-input", move || field.show_valid())
+                class=("invalid-input", move || field.show_invalid())
                 placeholder=" "
                 prop:value=password
-                required=move || if required {Some(true)} else {None}
+                required=move || if required.get() {Some(true)} else {None}
                 disabled=move || disabled
                 on:input=move |e| {
                     let val = event_target_value(&e);
                     password.update(|v| *v = val);
+                    generated.set(false);
                 }
-                on:focusout=move |e|{
-                    if required && event_target_value(&e).is_empty() {
-                        error_message.update(|v| *v = String::from("This field is required"));
-                        is_invalid_change.set(true);
-                        is_valid_change.set(false);
-                    }else if !required && event_target_value(&e).is_empty() {
-                        is_invalid_change.set(false);
-                        is_valid_change.set(true);
-                    }
+                on:keydown=detect_caps_lock
+                on:keyup=detect_caps_lock
+                on:paste=move |e| block_clipboard(allow_paste, e)
+                on:copy=move |e| block_clipboard(allow_copy, e)
+                on:cut=move |e| block_clipboard(allow_copy, e)
+                on:focusout=move |_| {
+                    caps_lock.set(false);
+                    field.touch();
                 }
             />
+            <div class="password-actions">
+                <Show when=move || reveal>
+                    <button
+                        type="button"
+                        class="password-action"
+                        aria-controls=input_id.clone()
+                        aria-pressed=move || visible.get().to_string()
+                        aria-label=move || {
+                            let key = if visible.get() { "password.hide" } else { "password.show" };
+                            translate(key, &[])
+                        }
+                        on:click=move |_| visible.update(|v| *v = !*v)
+                    >
+                        {move || if visible.get() {
+                            view! { <Icon icon=IoEyeOffOutline /> }
+                        } else {
+                            view! { <Icon icon=IoEyeOutline /> }
+                        }}
+                    </button>
+                </Show>
+                <Show when=move || has_generator>
+                    <button
+                        type="button"
+                        class="password-action"
+                        aria-label=move || translate("password.generate", &[])
+                        title=move || translate("password.generate", &[])
+                        on:click=generate_password.clone()
+                    >
+                        <Icon icon=IoKeyOutline />
+                    </button>
+                </Show>
+                <Show when=move || generated.get() && is_supported.get()>
+                    <button
+                        type="button"
+                        class="password-action"
+                        class:copied=copied
+                        aria-label=move || {
+                            let key = if copied.get() { "password.copied" } else { "password.copy" };
+                            translate(key, &[])
+                        }
+                        title=move || translate("password.copy", &[])
+                        on:click={
+                            let copy = copy.clone();
+                            move |_| copy(&password.get_untracked())
+                        }
+                    >
+                        {move || if copied.get() {
+                            view! { <Icon icon=IoCheckmarkOutline /> }
+                        } else {
+                            view! { <Icon icon=IoCopyOutline /> }
+                        }}
+                    </button>
+                </Show>
+            </div>
             <label
                 for=id()
                 class="input-label"
@@ -189,9 +396,18 @@ pub fn InputPassword(
             >
                 {label}
             </label>
-            <p class="input-error" class=("show-error", move || is_invalid_change.get() )>
+            <p class="input-warning" class=("show-warning", move || caps_lock.get()) role="status">
+                {move || translate("password.caps_lock", &[])}
+            </p>
+            <p class="input-error" class=("show-error", move || field.show_invalid() && !error_message().is_empty())>
                 {error_message}
             </p>
+            <Show when=move || strength_meter && !password.with(String::is_empty)>
+                <PasswordStrengthMeter strength=Signal::derive(move || estimate.map(|e| e.with(|e| e.strength)).unwrap_or_default()) />
+            </Show>
+            <Show when=move || checklist>
+                <PasswordChecklist checks=checks />
+            </Show>
         </div>
     }
 }
@@ -0,0 +1,29 @@
+use crate::RequirementCheck;
+use leptos::*;
+
+/// The requirements of a password, ticked off as they are met.
+///
+/// Rendered by [`InputPassword`](crate::InputPassword) when `checklist` is set, or on its own
+/// from the `checks` signal of the input.
+#[component]
+pub fn PasswordChecklist(
+    /// The result of each requirement
+    #[prop(into)]
+    checks: Signal<Vec<RequirementCheck>>,
+) -> impl IntoView {
+    view! {
+        <ul class="password-checklist">
+            <For
+                each=move || checks.get().into_iter().enumerate()
+                key=|(index, check)| (*index, check.requirement.clone(), check.met)
+                children=move |(_, check)| {
+                    view! {
+                        <li class="password-requirement" class:met=check.met>
+                            {move || check.requirement.message()}
+                        </li>
+                    }
+                }
+            />
+        </ul>
+    }
+}
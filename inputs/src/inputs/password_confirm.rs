@@ -0,0 +1,83 @@
+use crate::inputs::field::{validate_text, FieldState};
+use crate::{matches, translate, AnyValidator, PasswordInputStyle, ValidationMode, Validator};
+use leptos::MaybeSignal;
+use leptos::*;
+use uuid::Uuid;
+
+/// A password input that must match another password, for example on a signup form.
+/// It is validated again whenever either password changes.
+#[component]
+pub fn InputPasswordConfirm(
+    /// The password to match
+    #[prop(into)]
+    password: Signal<String>,
+    /// The tracked value
+    confirmation: RwSignal<String>,
+    /// The name of the input, used to match the errors returned by a server
+    #[prop(optional, into)]
+    name: Option<String>,
+    /// When the validation result is displayed, defaults to the mode of the form or of the theme
+    #[prop(optional)]
+    mode: Option<ValidationMode>,
+    /// Whether or not the input is required, defaults to `true`
+    #[prop(into, default = MaybeSignal::from(true))]
+    required: MaybeSignal<bool>,
+    /// Whether or not the input is disabled, defaults to `false`
+    #[prop(default = MaybeSignal::from(false))]
+    disabled: MaybeSignal<bool>,
+    /// The style of the input
+    #[prop(default = PasswordInputStyle::Underline)]
+    style: PasswordInputStyle,
+    /// Label for the input, defaults to `Confirm password`
+    #[prop(default = MaybeSignal::derive(|| translate("label.password_confirm", &[])))]
+    label: MaybeSignal<String>,
+) -> impl IntoView {
+    let id = Uuid::new_v4().to_string();
+    let id = move || id.clone();
+
+    let label = move || label.get();
+
+    let validator: AnyValidator = matches(password)
+        .with_message_key("password.mismatch")
+        .into();
+    let field = FieldState::new(name.clone(), mode, confirmation, move |confirmation| {
+        validate_text(confirmation, required, Some(&validator))
+    });
+
+    view! {
+        <div class="input-group">
+            <input
+                type="password"
+                autocomplete="new-password"
+                id=id()
+                name=name
+                class="input"
+                class:outline = style == PasswordInputStyle::Outline || style == PasswordInputStyle::Rounded
+                class:rounded = style == PasswordInputStyle::Rounded
+                class:underline = style == PasswordInputStyle::Underline
+                class=("valid-input", move || field.show_valid())
+                class=("invalid-input", move || field.show_invalid())
+                placeholder=" "
+                prop:value=confirmation
+                required=move || if required.get() {Some(true)} else {None}
+                disabled=move || disabled
+                on:input=move |e| {
+                    let val = event_target_value(&e);
+                    confirmation.update(|v| *v = val);
+                }
+                on:focusout=move |_| field.touch()
+            />
+            <label
+                for=id()
+                class="input-label"
+                class:outline = style == PasswordInputStyle::Outline || style == PasswordInputStyle::Rounded
+                class:underline = style == PasswordInputStyle::Underline
+            >
+                {label}
+            </label>
+            <p class="input-error" class=("show-error", move || field.show_invalid() )>
+                {move || field.error_message()}
+            </p>
+        </div>
+    }
+}
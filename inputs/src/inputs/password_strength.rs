@@ -0,0 +1,24 @@
+use crate::PasswordStrength;
+use leptos::*;
+
+/// A bar filled according to the strength of a password, with the name of the strength.
+///
+/// Rendered by [`InputPassword`](crate::InputPassword) when `strength_meter` is set, or on its own
+/// from the `strength` signal of the input.
+#[component]
+pub fn PasswordStrengthMeter(
+    /// The strength of the password
+    #[prop(into)]
+    strength: Signal<PasswordStrength>,
+) -> impl IntoView {
+    let score = move || strength.get().score();
+
+    view! {
+        <div class="password-strength" data-score=score>
+            <div class="password-strength-track">
+                <div class="password-strength-bar"></div>
+            </div>
+            <span class="password-strength-label">{move || strength.get().message()}</span>
+        </div>
+    }
+}
@@ -0,0 +1,353 @@
+use crate::inputs::suggestion_rows::{Row, Rows, ROW_HEIGHT};
+use leptos::ev::KeyboardEvent;
+use leptos::*;
+use uuid::Uuid;
+
+/// The default number of suggestions visible without scrolling.
+pub(crate) const DEFAULT_VISIBLE_SUGGESTIONS: usize = 5;
+
+/// The state of an input and of its list of suggestions, following the
+/// [WAI-ARIA combobox pattern](https://www.w3.org/WAI/ARIA/apg/patterns/combobox/).
+///
+/// The input keeps the focus, the highlighted suggestion is announced through
+/// `aria-activedescendant`.
+#[derive(Clone, Copy)]
+pub(crate) struct Combobox {
+    /// Whether the list is displayed
+    pub open: RwSignal<bool>,
+    /// The index of the suggestion chosen by `Enter`
+    pub highlighted: RwSignal<Option<usize>>,
+    /// The number of suggestions
+    len: Signal<usize>,
+    /// The number of suggestions visible without scrolling, the step of `PageUp` and `PageDown`
+    visible: usize,
+    list_id: StoredValue<String>,
+}
+
+impl Combobox {
+    pub fn new(len: Signal<usize>, visible: usize) -> Self {
+        Combobox {
+            open: create_rw_signal(false),
+            highlighted: create_rw_signal(None),
+            len,
+            visible: visible.max(1),
+            list_id: store_value(format!("suggestions-{}", Uuid::new_v4())),
+        }
+    }
+
+    pub fn list_id(&self) -> String {
+        self.list_id.get_value()
+    }
+
+    pub fn option_id(&self, index: usize) -> String {
+        format!("{}-{index}", self.list_id.get_value())
+    }
+
+    /// Whether the list is open and has suggestions, the value of `aria-expanded`.
+    pub fn expanded(&self) -> bool {
+        self.open.get() && self.len.get() > 0
+    }
+
+    /// The id of the highlighted suggestion, the value of `aria-activedescendant`.
+    pub fn active_descendant(&self) -> Option<String> {
+        let index = self.highlighted.get()?;
+        (self.expanded() && index < self.len.get()).then(|| self.option_id(index))
+    }
+
+    /// Opens the list as the user types, highlighting the first suggestion.
+    pub fn reopen(&self) {
+        self.highlighted.set(Some(0));
+        self.open.set(true);
+    }
+
+    pub fn close(&self) {
+        self.open.set(false);
+    }
+
+    /// Handles the keys of the combobox, and prevents their default action when they are handled.
+    ///
+    /// - `ArrowDown` and `ArrowUp` open the list, then move the highlight, wrapping around
+    /// - `Alt+ArrowDown` opens the list without highlighting a suggestion
+    /// - `Home`, `End`, `PageUp` and `PageDown` move the highlight in the open list
+    /// - `Enter` chooses the highlighted suggestion
+    /// - `Escape` closes the list, `Tab` closes it and moves the focus
+    pub fn on_keydown(&self, e: &KeyboardEvent, choose: Callback<usize>) {
+        let len = self.len.get_untracked();
+        let key = e.key();
+        if !self.open.get_untracked() {
+            let highlighted = match key.as_str() {
+                "ArrowDown" if e.alt_key() => None,
+                "ArrowDown" => Some(0),
+                "ArrowUp" => len.checked_sub(1),
+                _ => return,
+            };
+            e.prevent_default();
+            self.highlighted.set(highlighted);
+            self.open.set(true);
+            return;
+        }
+
+        match key.as_str() {
+            "Escape" => {
+                e.prevent_default();
+                self.close();
+                return;
+            }
+            "Tab" => {
+                self.close();
+                return;
+            }
+            _ => {}
+        }
+        let current = self
+            .highlighted
+            .get_untracked()
+            .map(|h| h.min(len.saturating_sub(1)));
+        if key == "Enter" {
+            if let Some(index) = current.filter(|_| len > 0) {
+                e.prevent_default();
+                choose.call(index);
+            }
+            return;
+        }
+        if let Some(next) = next_highlighted(&key, current, len, self.visible) {
+            e.prevent_default();
+            self.highlighted.set(Some(next));
+        }
+    }
+}
+
+/// The suggestion highlighted after a navigation key in an open list of `len` suggestions,
+/// `None` for the other keys and the empty lists.
+///
+/// The arrows wrap around, the pages of `visible` suggestions stop at the ends.
+fn next_highlighted(
+    key: &str,
+    current: Option<usize>,
+    len: usize,
+    visible: usize,
+) -> Option<usize> {
+    let last = len.checked_sub(1)?;
+    let current = current.map(|h| h.min(last));
+    let next = match key {
+        "ArrowDown" => current.map_or(0, |h| if h == last { 0 } else { h + 1 }),
+        "ArrowUp" => current.map_or(last, |h| if h == 0 { last } else { h - 1 }),
+        "Home" => 0,
+        "End" => last,
+        "PageDown" => current.map_or(0, |h| (h + visible).min(last)),
+        "PageUp" => current.map_or(0, |h| h.saturating_sub(visible)),
+        _ => return None,
+    };
+    Some(next)
+}
+
+/// The suggestions with the items of a group together, the groups in the order of their first item.
+pub(crate) fn group_suggestions<T: Clone>(items: Vec<T>, group: Callback<T, String>) -> Vec<T> {
+    let mut groups: Vec<(String, Vec<T>)> = Vec::new();
+    for item in items {
+        let name = group.call(item.clone());
+        match groups.iter_mut().find(|(g, _)| *g == name) {
+            Some((_, items)) => items.push(item),
+            None => groups.push((name, vec![item])),
+        }
+    }
+    groups.into_iter().flat_map(|(_, items)| items).collect()
+}
+
+/// The listbox of suggestions beneath an input, shared by the inputs with suggestions.
+///
+/// Only the rows in view are mounted, so a list of thousands of suggestions stays fast.
+/// The rows have a fixed height, and the list is sized per instance through inline
+/// custom properties.
+#[component]
+pub(crate) fn SuggestionList<T>(
+    /// The suggestions
+    items: Signal<Vec<T>>,
+    /// The state of the combobox
+    combobox: Combobox,
+    /// A unique key of a suggestion
+    key: Callback<T, String>,
+    /// Renders a suggestion
+    render: Callback<T, View>,
+    /// Called with the index of the suggestion clicked
+    on_choose: Callback<usize>,
+    /// The group of a suggestion, displayed above the first suggestion of the group
+    #[prop(optional_no_strip)]
+    group: Option<Callback<T, String>>,
+    #[prop(optional)] rounded: bool,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + 'static,
+{
+    let visible = combobox.visible;
+    let highlighted = combobox.highlighted;
+    let rows = create_memo(move |_| {
+        let items = if combobox.open.get() {
+            items.get()
+        } else {
+            Vec::new()
+        };
+        Rows::new(items, key, group)
+    });
+
+    let list = create_node_ref::<html::Ul>();
+    let scroll_top = create_rw_signal(0.0);
+    let window = create_memo(move |_| rows.with(|rows| rows.window(scroll_top.get(), visible)));
+
+    // Keeps the highlighted suggestion in view, and the scroll within the rows.
+    create_effect(move |_| {
+        let Some(list) = list.get() else {
+            return;
+        };
+        let (position, len) = rows.with(|rows| {
+            let position = highlighted.get().and_then(|index| rows.position(index));
+            (position, rows.len())
+        });
+        let height = visible as f64 * ROW_HEIGHT;
+        let mut top = f64::from(list.scroll_top());
+        if let Some(position) = position {
+            let row_top = position as f64 * ROW_HEIGHT;
+            if row_top < top {
+                top = row_top;
+            } else if row_top + ROW_HEIGHT > top + height {
+                top = row_top + ROW_HEIGHT - height;
+            }
+        }
+        let top = top.min(len as f64 * ROW_HEIGHT - height).max(0.0);
+        list.set_scroll_top(top as i32);
+        scroll_top.set(top);
+    });
+
+    let size = move || {
+        let len = rows.with(Rows::len).min(visible);
+        format!("--list-size: {len}; --list-max-rows: {visible}")
+    };
+    let padding = move || {
+        let (range, len) = (window.get(), rows.with(Rows::len));
+        let top = range.start as f64 * ROW_HEIGHT;
+        let bottom = (len - range.end) as f64 * ROW_HEIGHT;
+        format!("padding-top: {top}px; padding-bottom: {bottom}px")
+    };
+
+    view! {
+        <Show when=move || rows.with(|rows| rows.suggestions() > 0)>
+            <div class="suggestions" class:rounded=rounded style=size>
+                <ul
+                    id=combobox.list_id()
+                    role="listbox"
+                    style=padding
+                    node_ref=list
+                    on:scroll=move |e| {
+                        let list = event_target::<web_sys::Element>(&e);
+                        scroll_top.set(f64::from(list.scroll_top()));
+                    }
+                >
+                    <For
+                        each=move || rows.with(|rows| rows.slice(window.get()))
+                        key=|(key, _)| key.clone()
+                        children=move |(key, row)| match row {
+                            Row::Header(header) => {
+                                view! {
+                                    <li role="presentation" class="suggestion-group">{header}</li>
+                                }
+                            }
+                            Row::Suggestion(_, item) => {
+                                // The row is kept as the suggestion moves, its index follows.
+                                let index = create_memo(move |_| {
+                                    rows.with(|rows| rows.index(&key)).unwrap_or_default()
+                                });
+                                let selected = move || highlighted.get() == Some(index.get());
+                                view! {
+                                    <li
+                                        id=move || combobox.option_id(index.get())
+                                        role="option"
+                                        class="suggestion"
+                                        class:selected=selected
+                                        aria-selected=move || selected().to_string()
+                                        aria-setsize=move || rows.with(Rows::suggestions)
+                                        aria-posinset=move || index.get() + 1
+                                        on:mousedown=|e| e.prevent_default()
+                                        on:click=move |_| {
+                                            let index = index.get_untracked();
+                                            highlighted.set(Some(index));
+                                            on_choose.call(index);
+                                        }
+                                        on:mouseenter=move |_| highlighted.set(Some(index.get_untracked()))
+                                    >
+                                        {render.call(item)}
+                                    </li>
+                                }
+                            }
+                        }
+                    />
+                </ul>
+            </div>
+        </Show>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn next(key: &str, current: Option<usize>, len: usize) -> Option<usize> {
+        next_highlighted(key, current, len, 5)
+    }
+
+    #[test]
+    fn arrows_wrap_around() {
+        assert_eq!(next("ArrowDown", None, 3), Some(0));
+        assert_eq!(next("ArrowDown", Some(1), 3), Some(2));
+        assert_eq!(next("ArrowDown", Some(2), 3), Some(0));
+        assert_eq!(next("ArrowUp", None, 3), Some(2));
+        assert_eq!(next("ArrowUp", Some(1), 3), Some(0));
+        assert_eq!(next("ArrowUp", Some(0), 3), Some(2));
+        assert_eq!(next("ArrowDown", Some(0), 1), Some(0));
+    }
+
+    #[test]
+    fn pages_stop_at_the_ends() {
+        assert_eq!(next("PageDown", None, 20), Some(0));
+        assert_eq!(next("PageDown", Some(3), 20), Some(8));
+        assert_eq!(next("PageDown", Some(17), 20), Some(19));
+        assert_eq!(next("PageUp", None, 20), Some(0));
+        assert_eq!(next("PageUp", Some(8), 20), Some(3));
+        assert_eq!(next("PageUp", Some(2), 20), Some(0));
+        assert_eq!(next("Home", Some(8), 20), Some(0));
+        assert_eq!(next("End", None, 20), Some(19));
+    }
+
+    #[test]
+    fn highlight_beyond_a_shrunk_list_is_clamped() {
+        assert_eq!(next("ArrowDown", Some(9), 3), Some(0));
+        assert_eq!(next("ArrowUp", Some(9), 3), Some(1));
+        assert_eq!(next("PageUp", Some(9), 3), Some(0));
+    }
+
+    #[test]
+    fn empty_lists_and_other_keys() {
+        for key in ["ArrowDown", "ArrowUp", "Home", "End", "PageDown", "PageUp"] {
+            assert_eq!(next(key, None, 0), None, "{key}");
+            assert_eq!(next(key, Some(2), 0), None, "{key}");
+        }
+        assert_eq!(next("a", Some(0), 3), None);
+        assert_eq!(next("Enter", Some(0), 3), None);
+    }
+
+    #[test]
+    fn groups_keep_the_order_of_their_first_item() {
+        let runtime = create_runtime();
+        let items = ["Paris", "Lyon", "Berlin", "Nice", "Munich", "Rome"].map(String::from);
+        let country = Callback::new(|city: String| match city.as_str() {
+            "Berlin" | "Munich" => String::from("Germany"),
+            "Rome" => String::from("Italy"),
+            _ => String::from("France"),
+        });
+        assert_eq!(
+            group_suggestions(items.to_vec(), country),
+            ["Paris", "Lyon", "Nice", "Berlin", "Munich", "Rome"]
+        );
+        assert!(group_suggestions(Vec::<String>::new(), country).is_empty());
+        runtime.dispose();
+    }
+}
@@ -0,0 +1,177 @@
+use leptos::{Callable, Callback};
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// The height of a row of the list of suggestions, in pixels, as set by the styles.
+pub(crate) const ROW_HEIGHT: f64 = 52.0;
+/// The rows rendered above and below the visible ones, so a fast scroll shows no blank.
+const OVERSCAN: usize = 3;
+
+/// The key of a row.
+///
+/// A suggestion is keyed by its key and the number of suggestions with the same key before
+/// it, so duplicated suggestions are rendered once each, and a row is kept when its
+/// suggestion moves in the list.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum RowKey {
+    Header(String, usize),
+    Suggestion(String, usize),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Row<T> {
+    /// The header of a group
+    Header(String),
+    /// A suggestion, with its index among the suggestions
+    Suggestion(usize, T),
+}
+
+/// The suggestions laid out as rows, with the headers of their groups.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Rows<T> {
+    rows: Vec<(RowKey, Row<T>)>,
+    /// The row of each suggestion
+    positions: Vec<usize>,
+    /// The index of the suggestion of each key
+    indices: HashMap<RowKey, usize>,
+}
+
+impl<T: Clone> Rows<T> {
+    pub fn new(
+        items: Vec<T>,
+        key: Callback<T, String>,
+        group: Option<Callback<T, String>>,
+    ) -> Self {
+        let mut rows = Vec::with_capacity(items.len());
+        let mut positions = Vec::with_capacity(items.len());
+        let mut indices = HashMap::with_capacity(items.len());
+        // The key of a row, counting the rows with the same name before it.
+        let mut occurrences = HashMap::<RowKey, usize>::new();
+        let mut row_key = |kind: fn(String, usize) -> RowKey, name: String| {
+            let count = occurrences.entry(kind(name.clone(), 0)).or_default();
+            *count += 1;
+            kind(name, *count - 1)
+        };
+        let mut previous_group = None;
+        for (index, item) in items.into_iter().enumerate() {
+            if let Some(group) = group {
+                let name = group.call(item.clone());
+                if previous_group.as_ref() != Some(&name) {
+                    let key = row_key(RowKey::Header, name.clone());
+                    rows.push((key, Row::Header(name.clone())));
+                    previous_group = Some(name);
+                }
+            }
+            let key = row_key(RowKey::Suggestion, key.call(item.clone()));
+            positions.push(rows.len());
+            indices.insert(key.clone(), index);
+            rows.push((key, Row::Suggestion(index, item)));
+        }
+        Rows {
+            rows,
+            positions,
+            indices,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn suggestions(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// The index of the suggestion of the key, which changes as the suggestions change.
+    pub fn index(&self, key: &RowKey) -> Option<usize> {
+        self.indices.get(key).copied()
+    }
+
+    /// The row of the suggestion.
+    pub fn position(&self, index: usize) -> Option<usize> {
+        self.positions.get(index).copied()
+    }
+
+    /// The rows rendered when the list is scrolled by `scroll_top` pixels, with `visible` rows
+    /// shown at once.
+    pub fn window(&self, scroll_top: f64, visible: usize) -> Range<usize> {
+        let first = (scroll_top.max(0.0) / ROW_HEIGHT) as usize;
+        let start = first.saturating_sub(OVERSCAN).min(self.len());
+        let end = (first + visible + OVERSCAN).min(self.len());
+        start..end
+    }
+
+    pub fn slice(&self, range: Range<usize>) -> Vec<(RowKey, Row<T>)> {
+        self.rows[range].to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::create_runtime;
+
+    fn rows(items: &[&str]) -> Rows<String> {
+        let items = items.iter().map(|item| item.to_string()).collect();
+        Rows::new(items, Callback::new(|item: String| item), None)
+    }
+
+    fn keys(rows: &Rows<String>) -> Vec<RowKey> {
+        rows.slice(0..rows.len())
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect()
+    }
+
+    #[test]
+    fn duplicates_have_unique_keys() {
+        let runtime = create_runtime();
+        let rows = rows(&["paris", "lyon", "paris"]);
+        assert_eq!(
+            keys(&rows),
+            [
+                RowKey::Suggestion("paris".into(), 0),
+                RowKey::Suggestion("lyon".into(), 0),
+                RowKey::Suggestion("paris".into(), 1),
+            ]
+        );
+        assert_eq!(rows.index(&RowKey::Suggestion("paris".into(), 1)), Some(2));
+        runtime.dispose();
+    }
+
+    #[test]
+    fn keys_do_not_depend_on_the_position() {
+        let runtime = create_runtime();
+        let before = rows(&["paris", "lyon", "nice"]);
+        let after = rows(&["lyon", "nice"]);
+        let lyon = RowKey::Suggestion("lyon".into(), 0);
+        assert!(keys(&before).contains(&lyon) && keys(&after).contains(&lyon));
+        assert_eq!(before.index(&lyon), Some(1));
+        assert_eq!(after.index(&lyon), Some(0));
+        runtime.dispose();
+    }
+
+    #[test]
+    fn groups_have_headers() {
+        let runtime = create_runtime();
+        let items = ["a1", "a2", "b1"].map(String::from).to_vec();
+        let group = Callback::new(|item: String| item[..1].to_string());
+        let rows = Rows::new(items, Callback::new(|item: String| item), Some(group));
+        assert_eq!(rows.len(), 5);
+        assert_eq!(rows.suggestions(), 3);
+        assert_eq!(rows.position(2), Some(4));
+        assert_eq!(keys(&rows)[3], RowKey::Header("b".into(), 0));
+        runtime.dispose();
+    }
+
+    #[test]
+    fn window_covers_the_visible_rows() {
+        let runtime = create_runtime();
+        let items: Vec<String> = (0..100).map(|i| i.to_string()).collect();
+        let rows = Rows::new(items, Callback::new(|item: String| item), None);
+        assert_eq!(rows.window(0.0, 5), 0..8);
+        assert_eq!(rows.window(10.0 * ROW_HEIGHT, 5), 7..18);
+        assert_eq!(rows.window(99.0 * ROW_HEIGHT, 5), 96..100);
+        runtime.dispose();
+    }
+}
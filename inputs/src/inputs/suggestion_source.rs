@@ -0,0 +1,199 @@
+use crate::Matcher;
+use leptos::logging::warn;
+use leptos::{create_effect, create_local_resource, create_memo, create_rw_signal};
+use leptos::{Callable, Callback, MaybeSignal, Memo, RwSignal, Signal};
+use leptos::{SignalGet, SignalSet, SignalWith, SignalWithUntracked};
+use leptos_use::signal_debounced;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// The future returned by the fetch closure of a [`SuggestionSource`].
+pub type SuggestionFuture<T> = Pin<Box<dyn Future<Output = Result<Vec<T>, String>>>>;
+
+/// The closure of a [`SuggestionSource`] returning the suggestions of a query.
+pub type SuggestionFilter<T> = Rc<dyn Fn(&str) -> Vec<T>>;
+
+/// Where an [`Autocomplete`](crate::Autocomplete) gets the suggestions of the typed text from.
+pub enum SuggestionSource<T: 'static> {
+    /// A list, filtered and ranked by the displayed text of its items matching the query
+    List {
+        items: MaybeSignal<Vec<T>>,
+        matcher: Matcher,
+    },
+    /// A closure returning the suggestions of the query
+    Filter(SuggestionFilter<T>),
+    /// A closure fetching the suggestions of the query, called once the text stopped changing
+    /// for the debounce delay, and only if it is long enough
+    Fetch {
+        fetch: Rc<dyn Fn(String) -> SuggestionFuture<T>>,
+        debounce: Duration,
+        min_query_len: usize,
+    },
+}
+
+impl<T: Clone> Clone for SuggestionSource<T> {
+    fn clone(&self) -> Self {
+        match self {
+            SuggestionSource::List { items, matcher } => SuggestionSource::List {
+                items: items.clone(),
+                matcher: *matcher,
+            },
+            SuggestionSource::Filter(filter) => SuggestionSource::Filter(filter.clone()),
+            SuggestionSource::Fetch {
+                fetch,
+                debounce,
+                min_query_len,
+            } => SuggestionSource::Fetch {
+                fetch: fetch.clone(),
+                debounce: *debounce,
+                min_query_len: *min_query_len,
+            },
+        }
+    }
+}
+
+impl<T> SuggestionSource<T> {
+    /// The default delay between the last change of the text and the fetch.
+    pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(250);
+
+    /// Suggests the items of the list whose displayed text contains the query, ignoring the case
+    /// and the accents. Another [`Matcher`] is set by [`SuggestionSource::matcher`].
+    pub fn list(items: impl Into<MaybeSignal<Vec<T>>>) -> Self {
+        SuggestionSource::List {
+            items: items.into(),
+            matcher: Matcher::default(),
+        }
+    }
+
+    /// Suggests the items returned by the closure for the query.
+    pub fn filter(filter: impl Fn(&str) -> Vec<T> + 'static) -> Self {
+        SuggestionSource::Filter(Rc::new(filter))
+    }
+
+    /// Suggests the items fetched by the closure for the query, like the results of a search
+    /// endpoint. Failures are logged and suggest nothing.
+    pub fn fetch<F, Fut>(fetch: F) -> Self
+    where
+        F: Fn(String) -> Fut + 'static,
+        Fut: Future<Output = Result<Vec<T>, String>> + 'static,
+    {
+        SuggestionSource::Fetch {
+            fetch: Rc::new(move |query| Box::pin(fetch(query))),
+            debounce: Self::DEFAULT_DEBOUNCE,
+            min_query_len: 1,
+        }
+    }
+
+    /// Sets how the items of a list are filtered and ranked, like fuzzy matching.
+    pub fn matcher(mut self, with: Matcher) -> Self {
+        if let SuggestionSource::List { matcher, .. } = &mut self {
+            *matcher = with;
+        }
+        self
+    }
+
+    /// The matcher of a list, or the default matcher, used to highlight the suggestions.
+    pub(crate) fn highlighter(&self) -> Matcher {
+        match self {
+            SuggestionSource::List { matcher, .. } => *matcher,
+            _ => Matcher::default(),
+        }
+    }
+
+    /// Sets the delay between the last change of the text and the fetch.
+    pub fn debounce(mut self, delay: Duration) -> Self {
+        if let SuggestionSource::Fetch { debounce, .. } = &mut self {
+            *debounce = delay;
+        }
+        self
+    }
+
+    /// Sets the number of characters typed before the suggestions are fetched, defaults to 1.
+    pub fn min_query_len(mut self, len: usize) -> Self {
+        if let SuggestionSource::Fetch { min_query_len, .. } = &mut self {
+            *min_query_len = len;
+        }
+        self
+    }
+}
+
+/// The suggestions of the query, and whether newer suggestions are being fetched.
+///
+/// Fetched suggestions are tagged with their query, so the results of a query sent before
+/// the text changed are discarded, and the previous suggestions stay displayed meanwhile.
+pub(crate) fn use_suggestions<T>(
+    source: SuggestionSource<T>,
+    query: RwSignal<String>,
+    display: Callback<T, String>,
+) -> (Memo<Vec<T>>, Signal<bool>)
+where
+    T: Clone + PartialEq + 'static,
+{
+    match source {
+        SuggestionSource::List { items, matcher } => {
+            let suggestions = create_memo(move |_| {
+                query.with(|query| {
+                    items.with(|items| {
+                        matcher.filter(query, items, |item| display.call(item.clone()))
+                    })
+                })
+            });
+            (suggestions, Signal::derive(|| false))
+        }
+        SuggestionSource::Filter(filter) => {
+            let suggestions = create_memo(move |_| query.with(|q| filter(q)));
+            (suggestions, Signal::derive(|| false))
+        }
+        SuggestionSource::Fetch {
+            fetch,
+            debounce,
+            min_query_len,
+        } => {
+            let accepts = move |query: &str| query.trim().chars().count() >= min_query_len;
+            let fetched = create_rw_signal(None::<(String, Vec<T>)>);
+            let debounced = signal_debounced(query, debounce.as_millis() as f64);
+            let resource = create_local_resource(
+                move || debounced.get(),
+                move |query| {
+                    let fetch = fetch.clone();
+                    async move {
+                        if !accepts(&query) {
+                            return (query, Ok(Vec::new()));
+                        }
+                        let result = fetch(query.clone()).await;
+                        (query, result)
+                    }
+                },
+            );
+            create_effect(move |_| {
+                let Some((fetched_query, result)) = resource.get() else {
+                    return;
+                };
+                // The text changed since the query was sent, a newer query is on its way.
+                if query.with_untracked(|query| *query != fetched_query) {
+                    return;
+                }
+                let suggestions = result.unwrap_or_else(|error| {
+                    warn!("the suggestions could not be fetched: {error}");
+                    Vec::new()
+                });
+                fetched.set(Some((fetched_query, suggestions)));
+            });
+
+            let suggestions = create_memo(move |_| {
+                if query.with(|q| !accepts(q)) {
+                    return Vec::new();
+                }
+                fetched.with(|f| f.as_ref().map(|(_, s)| s.clone()).unwrap_or_default())
+            });
+            let loading = Signal::derive(move || {
+                let outdated = fetched
+                    .with(|f| query.with(|q| f.as_ref().map_or(true, |(fetched, _)| fetched != q)));
+                query.with(|q| accepts(q)) && outdated
+            });
+            (suggestions, loading)
+        }
+    }
+}
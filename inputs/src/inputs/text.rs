@@ -1,3 +1,5 @@
+use crate::inputs::field::{validate_text, FieldState};
+use crate::{AnyAsyncValidator, AnyValidator, ValidationMode};
 use leptos::component;
 use leptos::MaybeSignal;
 use leptos::*;
@@ -14,9 +16,15 @@ pub enum TextInputStyle {
 pub fn InputText(
     /// The tracked value
     value: RwSignal<String>,
+    /// The name of the input, used to match the errors returned by a server
+    #[prop(optional, into)]
+    name: Option<String>,
+    /// When the validation result is displayed, defaults to the mode of the form or of the theme
+    #[prop(optional)]
+    mode: Option<ValidationMode>,
     /// Whether or not the input is required, defaults to `false`
-    #[prop(default = false)]
-    required: bool,
+    #[prop(into, default = MaybeSignal::from(false))]
+    required: MaybeSignal<bool>,
     /// Whether or not the input is disabled, defaults to `false`
     #[prop(default = MaybeSignal::from(false))]
     disabled: MaybeSignal<bool>,
@@ -27,12 +35,16 @@ pub fn InputText(
     /// Block the entry if the input is invalid
     #[prop(optional, default=None, into)]
     validate_input: Option<fn(&str) -> bool>,
-    /// A function to validate the input value on change.
+    /// The validator of the input value on change.
     /// Will render color the input box.
     /// Green if valid, red if invalid
-    #[prop(optional, default=None, into)]
-    validate_change: Option<fn(&str) -> bool>,
-    /// The error message to display when the change is invalid
+    #[prop(optional, into)]
+    validator: Option<AnyValidator>,
+    /// The asynchronous validator of the input value, run once the value
+    /// stopped changing and passed the synchronous validation
+    #[prop(optional, into)]
+    async_validator: Option<AnyAsyncValidator>,
+    /// The error message displayed when the change is invalid
     #[prop(optional, into)]
     error_message: RwSignal<String>,
     /// The style to be applied to the input
@@ -42,61 +54,52 @@ pub fn InputText(
     let id = Uuid::new_v4().to_string();
     let id = move || id.clone();
 
-    let label = label.get();
-    let label = move || label.clone();
+    let label = move || label.get();
 
-    let is_invalid_change = create_rw_signal(false);
-    let is_valid_change = create_rw_signal(false);
+    let field = FieldState::new(name.clone(), mode, value, move |value| {
+        validate_text(value, required, validator.as_ref())
+    });
+
+    if let Some(async_validator) = async_validator {
+        field.validate_async(value, async_validator);
+    }
 
     let _ = watch(
-        move || value.get(),
-        move |value, _, _| {
-            let validator = validate_change.unwrap_or(|_| true);
-            if required && value.is_empty() {
-                is_valid_change.update(|v| *v = false);
-                is_invalid_change.update(|v| *v = true);
-                error_message.update(|v| *v = String::from("This field is required"));
-            } else if !validator(&value) {
-                is_valid_change.update(|v| *v = false);
-                is_invalid_change.update(|v| *v = true);
-            } else {
-                is_valid_change.update(|v| *v = true);
-                is_invalid_change.update(|v| *v = false);
+        move || field.current_error(),
+        move |error, _, _| {
+            if let Some(error) = error {
+                error_message.set(error.clone());
             }
         },
-        false,
+        true,
     );
 
     view! {
-        <div class="input-group">
+        <div class="input-group" class:pending=move || field.pending.get()>
             <input
                 type="text"
                 id=id()
+                name=name
                 class="input"
                 class:outline= style == TextInputStyle::Outline || style == TextInputStyle::Rounded
                 class:rounded= style == TextInputStyle::Rounded
                 class:underline= style == TextInputStyle::Underline
-                class=("valid-input", move || is_valid_change.get())
-                class=("invalid-input", move || is_invalid_change.get())
+                class=("valid-input", move || field.show_valid())
+                class=("invalid-input", move || field.show_invalid())
                 placeholder=" "
                 prop:value=value
-                required=move || if required {Some(true)} else {None}
+                required=move || if required.get() {Some(true)} else {None}
                 disabled=move || disabled
                 on:input=move |e| {
                     let val = event_target_value(&e);
-                    if validate_input.is_some() {
-                        if !validate_input.unwrap()(&val) {
+                    if let Some(validate_input) = validate_input {
+                        if !validate_input(&val) {
                             return;
                         }
                     }
                     value.update(|v| *v = val);
                 }
-                on:focusout=move |e|{
-                    if required && event_target_value(&e).is_empty() {
-                        error_message.update(|v| *v = String::from("This field is required"));
-                        is_invalid_change.set(true);
-                    }
-                }
+                on:focusout=move |_| field.touch()
             />
             <label for=id() class="input-label"
                 class:outline = {style  == TextInputStyle::Outline || style == TextInputStyle::Rounded}
@@ -104,7 +107,7 @@ pub fn InputText(
             >
                 {label}
             </label>
-            <p class="input-error" class=("show-error", move || is_invalid_change.get() )>{error_message}</p>
+            <p class="input-error" class=("show-error", move || field.show_invalid() )>{error_message}</p>
         </div>
     }
 }
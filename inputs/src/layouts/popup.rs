@@ -1,4 +1,4 @@
-use crate::{Button, ButtonSize, ButtonStyle, DisplayStrategy, Line};
+use crate::{translate, Button, ButtonSize, ButtonStyle, DisplayStrategy, Line};
 use icondata::IoClose;
 use leptos::html::Dialog;
 use leptos::{
@@ -71,7 +71,7 @@ pub fn Popup(
                 <Button
                     style=ButtonStyle::Text
                     size=ButtonSize::Small
-                    text="Close"
+                    text=MaybeSignal::derive(|| translate("popup.close", &[]))
                     content_after=view!{<Icon icon=IoClose class="close-icon"/>}
                     on:click=move |_| if let Some(on_close) = on_close { on_close() }else{ visible.set(false) }
                 />
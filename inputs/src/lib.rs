@@ -1,5 +1,20 @@
+mod address;
+mod i18n;
 mod inputs;
 mod layouts;
+mod matching;
+mod number;
+mod validation;
 
+pub use address::*;
+pub use i18n::*;
 pub use inputs::*;
 pub use layouts::*;
+pub use matching::*;
+pub use number::*;
+pub use validation::*;
+
+#[cfg(feature = "derive")]
+pub use leptos_inputs_derive::Form;
+/// The `validator` crate, whose errors are mapped onto the inputs of a form.
+pub use validator;
@@ -0,0 +1,52 @@
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// The characters of the text as they are compared, each with the index of the character
+/// of the text it comes from.
+///
+/// Accents are removed by decomposing the characters and dropping the combining marks, so
+/// `é` is compared as `e`. A character may fold into several, like `İ` into `i̇` once lowercased.
+pub(crate) fn fold(text: &str, ignore_case: bool, ignore_accents: bool) -> Vec<(char, usize)> {
+    let mut folded = Vec::with_capacity(text.len());
+    for (index, c) in text.chars().enumerate() {
+        let mut push = |c: char| {
+            if ignore_case {
+                folded.extend(c.to_lowercase().map(|c| (c, index)));
+            } else {
+                folded.push((c, index));
+            }
+        };
+        if ignore_accents {
+            c.nfd()
+                .filter(|c| !is_combining_mark(*c))
+                .for_each(&mut push);
+        } else {
+            push(c);
+        }
+    }
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn folds_case_and_accents() {
+        assert_eq!(fold("Éa", true, true), [('e', 0), ('a', 1)]);
+        assert_eq!(fold("Éa", false, true), [('E', 0), ('a', 1)]);
+        assert_eq!(fold("Éa", true, false), [('é', 0), ('a', 1)]);
+        // The combining mark of a decomposed character is dropped, keeping the indices.
+        assert_eq!(fold("e\u{301}x", true, true), [('e', 0), ('x', 2)]);
+    }
+
+    #[test]
+    fn characters_may_fold_into_several() {
+        assert_eq!(
+            fold("İs", true, false),
+            [('i', 0), ('\u{307}', 0), ('s', 1)]
+        );
+        assert_eq!(fold("İs", true, true), [('i', 0), ('s', 1)]);
+        assert_eq!(fold("ẞ", true, true), [('ß', 0)]);
+    }
+}
@@ -0,0 +1,352 @@
+use super::fold::fold;
+
+/// The score of a matched character.
+const MATCH: i32 = 16;
+/// The bonus of a character matched at the start of a word.
+const WORD_START: i32 = 8;
+/// The bonus of a character matched right after the previous one.
+const CONSECUTIVE: i32 = 8;
+/// The highest penalty of the characters before the first match.
+const MAX_LEADING_PENALTY: i32 = 15;
+
+/// How the query is looked for in the text of a suggestion.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MatchMode {
+    /// The text starts with the query
+    Prefix,
+    /// The text contains the query
+    #[default]
+    Substring,
+    /// The text contains the characters of the query in order, not necessarily adjacent,
+    /// like `mtvw` in `Mountain View`. Whitespaces of the query are ignored.
+    Fuzzy,
+}
+
+/// Where the query was found in a text, and how well it matched.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Match {
+    /// Higher when the characters are adjacent, start words, or come early in the text
+    pub score: i32,
+    /// The indices of the matched characters of the text, in characters and not in bytes
+    pub positions: Vec<usize>,
+}
+
+/// Filters and ranks suggestions by the text typed in an input.
+///
+/// Matches ignore the case and the accents by default, so `zurich` matches `Zürich`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Matcher {
+    mode: MatchMode,
+    ignore_case: bool,
+    ignore_accents: bool,
+    limit: Option<usize>,
+}
+
+impl Default for Matcher {
+    fn default() -> Self {
+        Matcher::new(MatchMode::default())
+    }
+}
+
+impl Matcher {
+    pub fn new(mode: MatchMode) -> Self {
+        Matcher {
+            mode,
+            ignore_case: true,
+            ignore_accents: true,
+            limit: None,
+        }
+    }
+
+    /// Whether `a` matches `A`, defaults to `true`.
+    pub fn ignore_case(mut self, ignore: bool) -> Self {
+        self.ignore_case = ignore;
+        self
+    }
+
+    /// Whether `e` matches `é`, defaults to `true`.
+    pub fn ignore_accents(mut self, ignore: bool) -> Self {
+        self.ignore_accents = ignore;
+        self
+    }
+
+    /// The maximal number of suggestions kept by [`Matcher::filter`], unlimited by default.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn mode(&self) -> MatchMode {
+        self.mode
+    }
+
+    /// Where the query is found in the text, if it is. An empty query matches any text.
+    pub fn find(&self, query: &str, text: &str) -> Option<Match> {
+        self.find_folded(&self.fold_query(query), text)
+    }
+
+    /// The items whose displayed text matches the query, the best matches first.
+    ///
+    /// Items matching equally keep their order.
+    pub fn filter<T: Clone>(
+        &self,
+        query: &str,
+        items: &[T],
+        display: impl Fn(&T) -> String,
+    ) -> Vec<T> {
+        let query = self.fold_query(query);
+        let mut matched: Vec<(i32, &T)> = items
+            .iter()
+            .filter_map(|item| {
+                let found = self.find_folded(&query, &display(item))?;
+                Some((found.score, item))
+            })
+            .collect();
+        matched.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        matched
+            .into_iter()
+            .take(self.limit.unwrap_or(usize::MAX))
+            .map(|(_, item)| item.clone())
+            .collect()
+    }
+
+    fn fold_query(&self, query: &str) -> Vec<char> {
+        fold(query.trim(), self.ignore_case, self.ignore_accents)
+            .into_iter()
+            .map(|(c, _)| c)
+            .filter(|c| self.mode != MatchMode::Fuzzy || !c.is_whitespace())
+            .collect()
+    }
+
+    fn find_folded(&self, query: &[char], text: &str) -> Option<Match> {
+        if query.is_empty() {
+            return Some(Match::default());
+        }
+        let text = fold(text, self.ignore_case, self.ignore_accents);
+        let (score, folded_positions) = match self.mode {
+            MatchMode::Prefix => find_at(query, &text, 0)?,
+            MatchMode::Substring => (0..=text.len().checked_sub(query.len())?)
+                .filter_map(|start| find_at(query, &text, start))
+                .max_by(|a, b| a.0.cmp(&b.0).then(b.1[0].cmp(&a.1[0])))?,
+            MatchMode::Fuzzy => find_fuzzy(query, &text)?,
+        };
+        let mut positions: Vec<usize> = folded_positions.iter().map(|&j| text[j].1).collect();
+        positions.dedup();
+        Some(Match { score, positions })
+    }
+}
+
+/// The score of the query character matched by the character `j` of the text.
+fn char_score(text: &[(char, usize)], j: usize) -> i32 {
+    let word_start = j == 0 || !text[j - 1].0.is_alphanumeric();
+    MATCH + if word_start { WORD_START } else { 0 }
+}
+
+fn leading_penalty(first: usize) -> i32 {
+    (first as i32).min(MAX_LEADING_PENALTY)
+}
+
+/// The query matched by the adjacent characters of the text from `start`.
+fn find_at(query: &[char], text: &[(char, usize)], start: usize) -> Option<(i32, Vec<usize>)> {
+    let window = text.get(start..start + query.len())?;
+    if !window.iter().zip(query).all(|((t, _), q)| t == q) {
+        return None;
+    }
+    let positions: Vec<usize> = (start..start + query.len()).collect();
+    let score = positions.iter().map(|&j| char_score(text, j)).sum::<i32>()
+        + CONSECUTIVE * (query.len() as i32 - 1)
+        - leading_penalty(start);
+    Some((score, positions))
+}
+
+/// The best placement of the characters of the query in order in the text.
+///
+/// `best[i][j]` is the best score of the first `i + 1` characters of the query, the last
+/// one matched by the character `j` of the text. Every character skipped between two matches
+/// costs a point, so the best previous match of a gap is tracked as the text is scanned.
+fn find_fuzzy(query: &[char], text: &[(char, usize)]) -> Option<(i32, Vec<usize>)> {
+    let (m, n) = (query.len(), text.len());
+    if m > n {
+        return None;
+    }
+    let mut best = vec![vec![None::<i32>; n]; m];
+    let mut previous = vec![vec![0usize; n]; m];
+    for i in 0..m {
+        // The highest `best[i - 1][k] + k` of the matches before a gap, with its `k`.
+        let mut gap_start = None::<(i32, usize)>;
+        for j in 0..n {
+            if i > 0 && j >= 2 {
+                let k = j - 2;
+                if let Some(score) = best[i - 1][k] {
+                    let candidate = score + k as i32;
+                    if gap_start.map_or(true, |(s, _)| candidate > s) {
+                        gap_start = Some((candidate, k));
+                    }
+                }
+            }
+            if text[j].0 != query[i] {
+                continue;
+            }
+            let gain = char_score(text, j);
+            if i == 0 {
+                best[0][j] = Some(gain - leading_penalty(j));
+                continue;
+            }
+            let mut found = None::<(i32, usize)>;
+            if let Some(score) = j.checked_sub(1).and_then(|k| best[i - 1][k]) {
+                found = Some((score + gain + CONSECUTIVE, j - 1));
+            }
+            if let Some((s, k)) = gap_start {
+                let score = s - (j as i32 - 1) + gain;
+                if found.map_or(true, |(f, _)| score > f) {
+                    found = Some((score, k));
+                }
+            }
+            if let Some((score, k)) = found {
+                best[i][j] = Some(score);
+                previous[i][j] = k;
+            }
+        }
+    }
+
+    let (mut j, score) = best[m - 1]
+        .iter()
+        .enumerate()
+        .filter_map(|(j, score)| Some((j, (*score)?)))
+        .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(&a.0)))?;
+    let mut positions = vec![0; m];
+    for i in (0..m).rev() {
+        positions[i] = j;
+        j = previous[i][j];
+    }
+    Some((score, positions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn positions(matcher: Matcher, query: &str, text: &str) -> Option<Vec<usize>> {
+        matcher.find(query, text).map(|found| found.positions)
+    }
+
+    fn ranked(matcher: Matcher, query: &str, items: &[&str]) -> Vec<String> {
+        let items: Vec<String> = items.iter().map(|item| item.to_string()).collect();
+        matcher.filter(query, &items, String::clone)
+    }
+
+    #[test]
+    fn prefix() {
+        let prefix = Matcher::new(MatchMode::Prefix);
+        assert_eq!(
+            positions(prefix, "mou", "Mountain View"),
+            Some(vec![0, 1, 2])
+        );
+        assert_eq!(positions(prefix, "view", "Mountain View"), None);
+        assert_eq!(positions(prefix, "", "Mountain View"), Some(vec![]));
+    }
+
+    #[test]
+    fn substring_prefers_word_starts_then_the_earliest() {
+        let substring = Matcher::new(MatchMode::Substring);
+        assert_eq!(
+            positions(substring, "ant", "Pantry Ant"),
+            Some(vec![7, 8, 9])
+        );
+        assert_eq!(positions(substring, "an", "Banana"), Some(vec![1, 2]));
+        // Past the highest leading penalty, equal matches keep the earliest.
+        let text = format!("{}abab", "x".repeat(16));
+        assert_eq!(positions(substring, "ab", &text), Some(vec![16, 17]));
+        assert_eq!(positions(substring, "abc", "ab"), None);
+    }
+
+    #[test]
+    fn fuzzy_matches_characters_in_order() {
+        let fuzzy = Matcher::new(MatchMode::Fuzzy);
+        assert_eq!(
+            positions(fuzzy, "mtvw", "Mountain View"),
+            Some(vec![0, 4, 9, 12])
+        );
+        assert_eq!(
+            positions(fuzzy, "mt vw", "Mountain View"),
+            Some(vec![0, 4, 9, 12])
+        );
+        assert_eq!(positions(fuzzy, "wv", "Mountain View"), None);
+        // Adjacent characters are preferred over the first occurrences.
+        assert_eq!(positions(fuzzy, "abc", "axbxc abc"), Some(vec![6, 7, 8]));
+        assert_eq!(positions(fuzzy, "ab", "axxxxb ab"), Some(vec![7, 8]));
+        // Equal placements keep the earliest characters.
+        assert_eq!(
+            positions(fuzzy, "sf", "Seaside San Francisco"),
+            Some(vec![0, 12])
+        );
+    }
+
+    #[test]
+    fn fuzzy_gaps_cost_a_point_per_character() {
+        let fuzzy = Matcher::new(MatchMode::Fuzzy);
+        let score = |text: &str| fuzzy.find("ab", text).unwrap().score;
+        assert_eq!(score("ab"), 2 * MATCH + WORD_START + CONSECUTIVE);
+        assert_eq!(score("axb"), 2 * MATCH + WORD_START - 1);
+        assert_eq!(score("axxxb"), 2 * MATCH + WORD_START - 3);
+    }
+
+    #[test]
+    fn ranks_the_best_matches_first() {
+        let substring = Matcher::new(MatchMode::Substring);
+        assert_eq!(
+            ranked(
+                substring,
+                "san",
+                &["Pleasanton", "San Jose", "Oakland", "Santa Clara"]
+            ),
+            ["San Jose", "Santa Clara", "Pleasanton"]
+        );
+        let fuzzy = Matcher::new(MatchMode::Fuzzy);
+        assert_eq!(
+            ranked(fuzzy, "sf", &["Seaford", "San Francisco", "Oslo"]),
+            ["San Francisco", "Seaford"]
+        );
+        assert_eq!(ranked(fuzzy, "", &["b", "a"]), ["b", "a"]);
+    }
+
+    #[test]
+    fn limit_caps_the_matches() {
+        let matcher = Matcher::new(MatchMode::Substring).limit(2);
+        assert_eq!(
+            ranked(matcher, "a", &["ab", "ba", "cc", "ad", "ae"]),
+            ["ab", "ad"]
+        );
+        assert_eq!(ranked(matcher.limit(0), "a", &["ab"]), Vec::<String>::new());
+    }
+
+    #[test]
+    fn positions_index_the_characters_of_the_text() {
+        let substring = Matcher::new(MatchMode::Substring);
+        assert_eq!(
+            positions(substring, "zurich", "Zürich"),
+            Some(vec![0, 1, 2, 3, 4, 5])
+        );
+        assert_eq!(positions(substring, "é", "Café"), Some(vec![3]));
+        assert_eq!(positions(substring, "é", "Cafe\u{301}"), Some(vec![3]));
+        assert_eq!(
+            positions(substring, "straß", "STRAẞE"),
+            Some(vec![0, 1, 2, 3, 4])
+        );
+        assert_eq!(
+            positions(substring, "istanbul", "İstanbul").map(|p| p.len()),
+            Some(8)
+        );
+        // Both characters folded from `İ` point at it once.
+        let exact_accents = substring.ignore_accents(false);
+        assert_eq!(
+            positions(exact_accents, "i\u{307}s", "İs"),
+            Some(vec![0, 1])
+        );
+        assert_eq!(positions(exact_accents, "zurich", "Zürich"), None);
+        assert_eq!(
+            positions(substring.ignore_case(false), "zurich", "Zürich"),
+            None
+        );
+    }
+}
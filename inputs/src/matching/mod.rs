@@ -0,0 +1,4 @@
+mod fold;
+mod matcher;
+
+pub use matcher::{Match, MatchMode, Matcher};
@@ -0,0 +1,192 @@
+use crate::Number;
+
+/// How the numbers are written in a locale: the decimal separator, and the separator
+/// of the groups of thousands.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NumberFormat {
+    decimal: char,
+    group: Option<char>,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        NumberFormat::new('.', Some(','))
+    }
+}
+
+impl NumberFormat {
+    pub const fn new(decimal: char, group: Option<char>) -> Self {
+        NumberFormat { decimal, group }
+    }
+
+    /// The format of the locale (`de-CH`) or of its language (`de`), or the English format
+    /// of an unknown locale.
+    pub fn for_locale(locale: &str) -> Self {
+        let locale = locale.replace('_', "-").to_lowercase();
+        let language = locale.split('-').next().unwrap_or_default();
+        match (locale.as_str(), language) {
+            ("de-ch" | "de-li" | "it-ch", _) => NumberFormat::new('.', Some('’')),
+            ("es-mx" | "es-us", _) => NumberFormat::new('.', Some(',')),
+            (_, "fr") => NumberFormat::new(',', Some('\u{202F}')),
+            (
+                _,
+                "de" | "es" | "it" | "pt" | "nl" | "da" | "id" | "tr" | "el" | "ro" | "hr" | "sl"
+                | "sr" | "vi",
+            ) => NumberFormat::new(',', Some('.')),
+            (
+                _,
+                "ru" | "uk" | "pl" | "cs" | "sk" | "bg" | "hu" | "fi" | "sv" | "nb" | "no" | "nn"
+                | "et" | "lt" | "lv",
+            ) => NumberFormat::new(',', Some('\u{A0}')),
+            _ => NumberFormat::default(),
+        }
+    }
+
+    pub fn decimal(&self) -> char {
+        self.decimal
+    }
+
+    pub fn group(&self) -> Option<char> {
+        self.group
+    }
+
+    /// The number with its thousands grouped, and with the decimals of the precision
+    /// if it is given.
+    pub fn format<T: Number>(&self, value: T, precision: Option<usize>) -> String {
+        let plain = match precision {
+            Some(precision) if !T::INTEGER => format!("{value:.precision$}"),
+            _ => value.to_string(),
+        };
+        let (sign, unsigned) = match plain.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", plain.as_str()),
+        };
+        let (integer, fraction) = match unsigned.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (unsigned, None),
+        };
+
+        let mut formatted = String::from(sign);
+        for (index, digit) in integer.chars().enumerate() {
+            let remaining = integer.len() - index;
+            if index > 0 && remaining % 3 == 0 {
+                formatted.extend(self.group);
+            }
+            formatted.push(digit);
+        }
+        if let Some(fraction) = fraction {
+            formatted.push(self.decimal);
+            formatted.push_str(fraction);
+        }
+        formatted
+    }
+
+    /// The number written in the text, ignoring the separators of thousands and the spaces.
+    ///
+    /// A point is read as the decimal separator when it does not separate thousands, so
+    /// `1.5` is read as `1,5` in French. The ASCII apostrophe is read as the typographic one,
+    /// so `1'234` is read in Swiss German.
+    pub fn parse<T: Number>(&self, text: &str) -> Option<T> {
+        let normalized: String = text
+            .trim()
+            .chars()
+            .filter(|c| !self.is_group(*c) && !c.is_whitespace())
+            .map(|c| match c {
+                c if c == self.decimal => '.',
+                '\u{2212}' => '-',
+                c => c,
+            })
+            .collect();
+        if normalized.is_empty() {
+            return None;
+        }
+        normalized
+            .parse::<T>()
+            .ok()
+            .filter(|value| value.to_f64().is_finite())
+    }
+
+    fn is_group(&self, c: char) -> bool {
+        Some(c) == self.group || (c == '\'' && self.group == Some('’'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip<T: Number + std::fmt::Debug>(locale: &str, value: T, expected: &str) {
+        let format = NumberFormat::for_locale(locale);
+        let formatted = format.format(value, None);
+        assert_eq!(formatted, expected, "{locale}");
+        assert_eq!(format.parse::<T>(&formatted), Some(value), "{locale}");
+    }
+
+    #[test]
+    fn formats_and_parses_back() {
+        round_trip("en", 1234567.5f64, "1,234,567.5");
+        round_trip("en-US", -1234i32, "-1,234");
+        round_trip("fr", 1234567.25f64, "1\u{202F}234\u{202F}567,25");
+        round_trip("fr-CA", 999u32, "999");
+        round_trip("de", -1234.5f64, "-1.234,5");
+        round_trip("de-AT", 1000000i64, "1.000.000");
+        round_trip("de-CH", 1234.5f64, "1’234.5");
+        round_trip("de_ch", -12345i32, "-12’345");
+        round_trip("sv", 1234.5f32, "1\u{A0}234,5");
+        round_trip("xx", 0u8, "0");
+    }
+
+    #[test]
+    fn parses_typed_separators() {
+        let fr = NumberFormat::for_locale("fr");
+        for text in [
+            "1 234,5",
+            "1\u{A0}234,5",
+            "1\u{202F}234,5",
+            "1234,5",
+            "1234.5",
+            " 1234,5 ",
+        ] {
+            assert_eq!(fr.parse::<f64>(text), Some(1234.5), "{text:?}");
+        }
+        let de_ch = NumberFormat::for_locale("de-CH");
+        assert_eq!(de_ch.parse::<i32>("1'234"), Some(1234));
+        assert_eq!(de_ch.parse::<f64>("1’234'567.5"), Some(1234567.5));
+        assert_eq!(NumberFormat::for_locale("de").parse::<i32>("1'234"), None);
+        let de = NumberFormat::for_locale("de");
+        assert_eq!(de.parse::<f64>("1.234,5"), Some(1234.5));
+        assert_eq!(de.parse::<f64>("1.5"), Some(15.0));
+    }
+
+    #[test]
+    fn parses_signs() {
+        let en = NumberFormat::default();
+        assert_eq!(en.parse::<i32>("-1,234"), Some(-1234));
+        assert_eq!(en.parse::<i32>("\u{2212}1,234"), Some(-1234));
+        assert_eq!(en.parse::<f64>("\u{2212}0.5"), Some(-0.5));
+        assert_eq!(en.parse::<u32>("-1"), None);
+    }
+
+    #[test]
+    fn rejects_what_is_not_a_number() {
+        let en = NumberFormat::default();
+        for text in ["", "  ", "abc", "1.2.3", "1e400", "inf", "NaN", "--1"] {
+            assert_eq!(en.parse::<f64>(text), None, "{text:?}");
+        }
+        assert_eq!(en.parse::<u8>("256"), None);
+        assert_eq!(en.parse::<i32>("1.5"), None);
+    }
+
+    #[test]
+    fn precision() {
+        let en = NumberFormat::default();
+        assert_eq!(en.format(1234.5f64, Some(2)), "1,234.50");
+        assert_eq!(en.format(1234.567f64, Some(1)), "1,234.6");
+        assert_eq!(en.format(-999.75f32, Some(0)), "-1,000");
+        assert_eq!(en.format(1234i32, Some(2)), "1,234");
+        let fr = NumberFormat::for_locale("fr");
+        let formatted = fr.format(-1234.5f64, Some(2));
+        assert_eq!(formatted, "-1\u{202F}234,50");
+        assert_eq!(fr.parse::<f64>(&formatted), Some(-1234.5));
+    }
+}
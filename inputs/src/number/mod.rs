@@ -0,0 +1,5 @@
+mod format;
+mod value;
+
+pub use format::NumberFormat;
+pub use value::Number;
@@ -0,0 +1,95 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A number entered in an [`InputNumber`](crate::InputNumber), implemented by the primitive
+/// integer and float types.
+pub trait Number: Copy + PartialOrd + Display + FromStr + 'static {
+    /// Whether the type only holds whole numbers
+    const INTEGER: bool;
+    const ZERO: Self;
+    const ONE: Self;
+    /// The relative precision of the type, to compare numbers computed as floats
+    const EPSILON: f64;
+
+    /// The sum, or `None` if it overflows.
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// The difference, or `None` if it overflows.
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    /// The number rounded to the decimals. Integers are returned as they are.
+    fn round_to(self, decimals: usize) -> Self;
+
+    /// The number as a float, to check it is a multiple of a step.
+    fn to_f64(self) -> f64;
+
+    /// The number closest to the float, rounded for integers.
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {$(
+        impl Number for $t {
+            const INTEGER: bool = true;
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const EPSILON: f64 = f64::EPSILON;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$t>::checked_sub(self, other)
+            }
+
+            fn round_to(self, _decimals: usize) -> Self {
+                self
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn from_f64(value: f64) -> Self {
+                value.round() as $t
+            }
+        }
+    )*};
+}
+
+macro_rules! float {
+    ($($t:ty),*) => {$(
+        impl Number for $t {
+            const INTEGER: bool = false;
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const EPSILON: f64 = <$t>::EPSILON as f64;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                Some(self + other).filter(|sum| sum.is_finite())
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                Some(self - other).filter(|difference| difference.is_finite())
+            }
+
+            fn round_to(self, decimals: usize) -> Self {
+                let factor = (10 as $t).powi(decimals as i32);
+                let rounded = (self * factor).round() / factor;
+                if rounded.is_finite() { rounded } else { self }
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn from_f64(value: f64) -> Self {
+                value as $t
+            }
+        }
+    )*};
+}
+
+integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+float!(f32, f64);
@@ -0,0 +1,94 @@
+use crate::validation::ValidationError;
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::time::Duration;
+use web_sys::AbortSignal;
+
+/// The future returned by an [`AsyncValidator`].
+pub type ValidationFuture = Pin<Box<dyn Future<Output = Result<(), ValidationError>>>>;
+
+/// A validation rule that needs to wait for a result, for example from a server.
+///
+/// Any closure `Fn(String) -> impl Future<Output = Result<(), ValidationError>>` is an
+/// asynchronous validator.
+pub trait AsyncValidator {
+    /// Validates the value, returning the reason of the failure if it is rejected.
+    fn validate(&self, value: String) -> ValidationFuture;
+
+    /// Validates the value, cancelling the validation once the signal is aborted, like when
+    /// the value changed in the meantime.
+    ///
+    /// Defaults to [`validate`](Self::validate), ignoring the signal.
+    fn validate_abortable(&self, value: String, signal: &AbortSignal) -> ValidationFuture {
+        let _ = signal;
+        self.validate(value)
+    }
+}
+
+impl<F, Fut> AsyncValidator for F
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<(), ValidationError>> + 'static,
+{
+    fn validate(&self, value: String) -> ValidationFuture {
+        Box::pin(self(value))
+    }
+}
+
+/// A type erased [`AsyncValidator`], as accepted by the inputs.
+///
+/// The validation runs once the value stopped changing for the debounce delay,
+/// and only if the synchronous validation of the input succeeded.
+/// A validation still running when the next value is validated is aborted, and the results
+/// of values that changed in the meantime are discarded.
+#[derive(Clone)]
+pub struct AnyAsyncValidator {
+    validator: Rc<dyn AsyncValidator>,
+    debounce: Duration,
+}
+
+impl AnyAsyncValidator {
+    /// The default delay between the last change of the value and the validation.
+    pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(300);
+
+    pub fn new(validator: impl AsyncValidator + 'static) -> Self {
+        AnyAsyncValidator {
+            validator: Rc::new(validator),
+            debounce: Self::DEFAULT_DEBOUNCE,
+        }
+    }
+
+    /// Sets the delay between the last change of the value and the validation.
+    pub fn debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    pub fn debounce_delay(&self) -> Duration {
+        self.debounce
+    }
+
+    pub fn validate(&self, value: String) -> ValidationFuture {
+        self.validator.validate(value)
+    }
+
+    pub fn validate_abortable(&self, value: String, signal: &AbortSignal) -> ValidationFuture {
+        self.validator.validate_abortable(value, signal)
+    }
+}
+
+impl<V: AsyncValidator + 'static> From<V> for AnyAsyncValidator {
+    fn from(validator: V) -> Self {
+        AnyAsyncValidator::new(validator)
+    }
+}
+
+impl Debug for AnyAsyncValidator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AnyAsyncValidator")
+            .field("debounce", &self.debounce)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,50 @@
+use super::{BreachFuture, BreachedPasswordSource};
+use std::collections::HashSet;
+use std::future::ready;
+use std::rc::Rc;
+
+/// The most common passwords, sorted by frequency.
+const COMMON_PASSWORDS: &str = include_str!("passwords.txt");
+
+/// A list of common passwords bundled with the crate, checked without any request.
+#[derive(Clone, Debug)]
+pub struct EmbeddedPasswordList {
+    passwords: Rc<HashSet<String>>,
+}
+
+impl Default for EmbeddedPasswordList {
+    fn default() -> Self {
+        Self::top(usize::MAX)
+    }
+}
+
+impl EmbeddedPasswordList {
+    /// The `n` most common passwords of the bundled list.
+    pub fn top(n: usize) -> Self {
+        let passwords = COMMON_PASSWORDS.lines().take(n).map(String::from).collect();
+        EmbeddedPasswordList {
+            passwords: Rc::new(passwords),
+        }
+    }
+
+    /// Adds passwords to the list, like the leaked passwords of your own service.
+    pub fn with<I, S>(mut self, passwords: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Rc::make_mut(&mut self.passwords).extend(passwords.into_iter().map(Into::into));
+        self
+    }
+
+    /// Whether the password, or its lowercase version, is in the list.
+    pub fn contains(&self, password: &str) -> bool {
+        self.passwords.contains(password) || self.passwords.contains(&password.to_lowercase())
+    }
+}
+
+impl BreachedPasswordSource for EmbeddedPasswordList {
+    fn is_breached(&self, password: String) -> BreachFuture {
+        Box::pin(ready(Ok(self.contains(&password))))
+    }
+}
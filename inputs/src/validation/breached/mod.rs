@@ -0,0 +1,8 @@
+mod embedded;
+mod range;
+mod sha1;
+mod source;
+
+pub use embedded::EmbeddedPasswordList;
+pub use range::RangeApiSource;
+pub use source::{not_breached, BreachFuture, BreachedPasswordSource, NotBreached};
@@ -0,0 +1,117 @@
+use super::sha1::sha1_hex;
+use super::{BreachFuture, BreachedPasswordSource};
+use gloo_net::http::Request;
+
+/// Checks the passwords against a k-anonymity range endpoint, like the one of Have I Been Pwned.
+///
+/// Only the first 5 characters of the SHA-1 hash of the password are sent, appended to the
+/// endpoint. The endpoint answers with the suffixes of the known hashes starting with
+/// this prefix, one `SUFFIX:COUNT` per line.
+#[derive(Clone, Debug)]
+pub struct RangeApiSource {
+    endpoint: String,
+    min_count: u64,
+}
+
+impl Default for RangeApiSource {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_ENDPOINT)
+    }
+}
+
+impl RangeApiSource {
+    /// The range endpoint of Have I Been Pwned.
+    pub const DEFAULT_ENDPOINT: &'static str = "https://api.pwnedpasswords.com/range/";
+
+    /// Uses the endpoint, for example a local stand-in server in tests.
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        RangeApiSource {
+            endpoint: endpoint.into(),
+            min_count: 1,
+        }
+    }
+
+    /// Only rejects the passwords found at least `count` times, defaults to 1.
+    pub fn min_count(mut self, count: u64) -> Self {
+        self.min_count = count;
+        self
+    }
+
+    /// The url of the range of the password, and the suffix of its hash in the range.
+    fn range(&self, password: &str) -> (String, String) {
+        let hash = sha1_hex(password.as_bytes());
+        let (prefix, suffix) = hash.split_at(5);
+        (format!("{}{}", self.endpoint, prefix), suffix.to_string())
+    }
+
+    /// Whether the range lists the suffix at least `min_count` times. The padding lines
+    /// have a count of 0.
+    fn is_listed(&self, body: &str, suffix: &str) -> bool {
+        body.lines().any(|line| match line.trim().split_once(':') {
+            Some((found, count)) => {
+                found.eq_ignore_ascii_case(suffix)
+                    && count
+                        .trim()
+                        .parse::<u64>()
+                        .is_ok_and(|count| count > 0 && count >= self.min_count)
+            }
+            None => false,
+        })
+    }
+}
+
+impl BreachedPasswordSource for RangeApiSource {
+    fn is_breached(&self, password: String) -> BreachFuture {
+        let (url, suffix) = self.range(&password);
+        let source = self.clone();
+
+        Box::pin(async move {
+            let response = Request::get(&url)
+                .header("Add-Padding", "true")
+                .send()
+                .await
+                .map_err(|e| e.to_string())?;
+            if !response.ok() {
+                return Err(format!("{url} answered with status {}", response.status()));
+            }
+            let body = response.text().await.map_err(|e| e.to_string())?;
+            Ok(source.is_listed(&body, &suffix))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BODY: &str = "0018A45C4D1DEF81644B54AB7F969B88D65:10\r\n\
+        1E4C9B93F3F0682250B6CF8331B7EE68FD8:3\r\n\
+        011053FD0102E94D6AE2F8B83D76FAF94F6:0\r\n";
+
+    #[test]
+    fn requests_the_range_of_the_prefix() {
+        let source = RangeApiSource::new("http://localhost:8080/range/");
+        let (url, suffix) = source.range("password");
+        assert_eq!(url, "http://localhost:8080/range/5BAA6");
+        assert_eq!(suffix, "1E4C9B93F3F0682250B6CF8331B7EE68FD8");
+    }
+
+    #[test]
+    fn finds_the_suffix_in_the_range() {
+        let source = RangeApiSource::default();
+        assert!(source.is_listed(BODY, "1E4C9B93F3F0682250B6CF8331B7EE68FD8"));
+        assert!(source.is_listed(BODY, "1e4c9b93f3f0682250b6cf8331b7ee68fd8"));
+        assert!(!source.is_listed(BODY, "1E4C9B93F3F0682250B6CF8331B7EE68FD9"));
+        assert!(!source.is_listed("", "1E4C9B93F3F0682250B6CF8331B7EE68FD8"));
+        assert!(!source.is_listed("garbage\nmore garbage", "garbage"));
+    }
+
+    #[test]
+    fn ignores_padding_and_rare_passwords() {
+        let source = RangeApiSource::default();
+        assert!(!source.is_listed(BODY, "011053FD0102E94D6AE2F8B83D76FAF94F6"));
+        let source = source.min_count(5);
+        assert!(!source.is_listed(BODY, "1E4C9B93F3F0682250B6CF8331B7EE68FD8"));
+        assert!(source.is_listed(BODY, "0018A45C4D1DEF81644B54AB7F969B88D65"));
+    }
+}
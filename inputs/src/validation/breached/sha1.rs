@@ -0,0 +1,88 @@
+/// The SHA-1 digest of the bytes, as uppercase hexadecimal.
+///
+/// Only used to build the k-anonymity prefix of a password, not for security.
+pub(crate) fn sha1_hex(bytes: &[u8]) -> String {
+    let mut h: [u32; 5] = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
+
+    let mut message = bytes.to_vec();
+    let bit_len = (bytes.len() as u64).wrapping_mul(8);
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&bit_len.to_be_bytes());
+
+    for block in message.chunks_exact(64) {
+        let mut w = [0u32; 80];
+        for (i, word) in block.chunks_exact(4).enumerate() {
+            w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for i in 16..80 {
+            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e] = h;
+        for (i, word) in w.iter().enumerate() {
+            let (f, k) = match i {
+                0..=19 => ((b & c) | (!b & d), 0x5A827999),
+                20..=39 => (b ^ c ^ d, 0x6ED9EBA1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8F1BBCDC),
+                _ => (b ^ c ^ d, 0xCA62C1D6),
+            };
+            let temp = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(*word);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+        }
+        for (value, added) in h.iter_mut().zip([a, b, c, d, e]) {
+            *value = value.wrapping_add(added);
+        }
+    }
+
+    h.iter().map(|value| format!("{value:08X}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_the_reference_vectors() {
+        let vectors: [(&[u8], &str); 5] = [
+            (b"", "DA39A3EE5E6B4B0D3255BFEF95601890AFD80709"),
+            (b"abc", "A9993E364706816ABA3E25717850C26C9CD0D89D"),
+            (
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+                "84983E441C3BD26EBAAE4AA1F95129E5E54670F1",
+            ),
+            (b"password", "5BAA61E4C9B93F3F0682250B6CF8331B7EE68FD8"),
+            (
+                "pässword".as_bytes(),
+                "23B74494475F5F874980B7676D511E23D886DA64",
+            ),
+        ];
+        for (bytes, digest) in vectors {
+            assert_eq!(sha1_hex(bytes), digest);
+        }
+    }
+
+    #[test]
+    fn pads_at_the_block_boundaries() {
+        let vectors = [
+            (55, "C1C8BBDC22796E28C0E15163D20899B65621D65A"),
+            (56, "C2DB330F6083854C99D4B5BFB6E8F29F201BE699"),
+            (64, "0098BA824B5C16427BD7A1122A5A442A25EC644D"),
+            (1000, "291E9A6C66994949B57BA5E650361E98FC36B1BA"),
+        ];
+        for (len, digest) in vectors {
+            assert_eq!(sha1_hex(&vec![b'a'; len]), digest, "{len} bytes");
+        }
+    }
+}
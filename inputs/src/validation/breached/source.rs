@@ -0,0 +1,76 @@
+use crate::validation::no_params;
+use crate::{AsyncValidator, ValidationError, ValidationFuture};
+use std::future::Future;
+use std::pin::Pin;
+
+/// The future returned by a [`BreachedPasswordSource`].
+pub type BreachFuture = Pin<Box<dyn Future<Output = Result<bool, String>>>>;
+
+/// A list of passwords known to be breached or too common.
+pub trait BreachedPasswordSource {
+    /// Whether the password is in the list, or the reason the list could not be checked.
+    fn is_breached(&self, password: String) -> BreachFuture;
+}
+
+/// Rejects the passwords of a [`BreachedPasswordSource`], see [`not_breached`].
+pub struct NotBreached<S>(S);
+
+/// Rejects the passwords of the source.
+///
+/// The password is accepted if the source could not be checked, so an unreachable
+/// endpoint does not prevent users from signing up.
+pub fn not_breached<S: BreachedPasswordSource>(source: S) -> NotBreached<S> {
+    NotBreached(source)
+}
+
+impl<S: BreachedPasswordSource> AsyncValidator for NotBreached<S> {
+    fn validate(&self, value: String) -> ValidationFuture {
+        let breached = self.0.is_breached(value);
+        Box::pin(async move {
+            match breached.await {
+                Ok(true) => Err(ValidationError::from_key("password.breached", no_params())),
+                Ok(false) => Ok(()),
+                Err(error) => {
+                    leptos::logging::warn!("the breached passwords could not be checked: {error}");
+                    Ok(())
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    /// A source answering every password with the same result, like an unreachable endpoint.
+    struct Answer(Result<bool, String>);
+
+    impl BreachedPasswordSource for Answer {
+        fn is_breached(&self, _: String) -> BreachFuture {
+            let answer = self.0.clone();
+            Box::pin(async move { answer })
+        }
+    }
+
+    #[test]
+    fn rejects_breached_passwords() {
+        let validator = not_breached(Answer(Ok(true)));
+        let error = block_on(validator.validate(String::from("password"))).unwrap_err();
+        assert_eq!(
+            error,
+            ValidationError::from_key("password.breached", no_params())
+        );
+        assert!(block_on(not_breached(Answer(Ok(false))).validate(String::from("x"))).is_ok());
+    }
+
+    #[test]
+    fn fails_open_on_network_errors() {
+        let validator = not_breached(Answer(Err(String::from("failed to fetch"))));
+        assert_eq!(
+            block_on(validator.validate(String::from("password"))),
+            Ok(())
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use crate::validation::no_params;
+use crate::validation::{ValidationError, Validator};
+use leptos::{MaybeSignal, Signal, SignalGet, SignalWith};
+
+/// The value must be equal to the value of another input, see [`matches`].
+#[derive(Clone)]
+pub struct Matches(Signal<String>);
+
+/// The value must be equal to the value of another input.
+/// The input is validated again whenever the other value changes.
+pub fn matches(other: impl Into<Signal<String>>) -> Matches {
+    Matches(other.into())
+}
+
+impl Validator for Matches {
+    fn validate(&self, value: &str) -> Result<(), ValidationError> {
+        if self.0.with(|other| other != value) {
+            return Err(ValidationError::from_key("matches", no_params()));
+        }
+        Ok(())
+    }
+}
+
+/// The value is compared with the value of another input, see [`cross_field`].
+#[derive(Clone)]
+pub struct CrossField<T: 'static, F> {
+    other: Signal<T>,
+    predicate: F,
+    message: String,
+}
+
+/// The value must satisfy the predicate, given the value of another input.
+/// The input is validated again whenever the other value changes.
+///
+/// For example, an end date after a start date:
+/// `cross_field(start, |end, start: &String| end > start.as_str(), "The end must be after the start.")`
+pub fn cross_field<T, F>(
+    other: impl Into<Signal<T>>,
+    predicate: F,
+    message: impl Into<String>,
+) -> CrossField<T, F>
+where
+    F: Fn(&str, &T) -> bool,
+{
+    CrossField {
+        other: other.into(),
+        predicate,
+        message: message.into(),
+    }
+}
+
+impl<T, F> Validator for CrossField<T, F>
+where
+    F: Fn(&str, &T) -> bool,
+{
+    fn validate(&self, value: &str) -> Result<(), ValidationError> {
+        if !self.other.with(|other| (self.predicate)(value, other)) {
+            return Err(ValidationError::new(self.message.clone()));
+        }
+        Ok(())
+    }
+}
+
+/// An input is required as long as the other input is empty.
+///
+/// Given to the `required` prop of two inputs, at least one of them must be filled.
+pub fn required_unless(other: impl Into<Signal<String>>) -> MaybeSignal<bool> {
+    let other = other.into();
+    Signal::derive(move || other.get().is_empty()).into()
+}
@@ -0,0 +1,27 @@
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+/// Domains of well-known disposable email services.
+const DISPOSABLE_DOMAINS: &str = include_str!("disposable_domains.txt");
+
+fn disposable_domains() -> &'static HashSet<&'static str> {
+    static DOMAINS: OnceLock<HashSet<&'static str>> = OnceLock::new();
+    DOMAINS.get_or_init(|| DISPOSABLE_DOMAINS.lines().collect())
+}
+
+/// Whether the domain, or one of its parent domains, belongs to a disposable email service
+/// of the list bundled with the crate.
+pub fn is_disposable_domain(domain: &str) -> bool {
+    let domain = domain.trim_end_matches('.').to_lowercase();
+    let domains = disposable_domains();
+    let mut candidate = domain.as_str();
+    loop {
+        if domains.contains(candidate) {
+            return true;
+        }
+        match candidate.split_once('.') {
+            Some((_, parent)) if parent.contains('.') => candidate = parent,
+            _ => return false,
+        }
+    }
+}
@@ -0,0 +1,7 @@
+mod disposable;
+mod mode;
+mod policy;
+
+pub use disposable::is_disposable_domain;
+pub use mode::EmailMode;
+pub use policy::EmailPolicy;
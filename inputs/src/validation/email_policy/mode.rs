@@ -0,0 +1,202 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// The maximal length of an address, from RFC 5321.
+const MAX_LEN: usize = 254;
+/// The maximal length of the local part, from RFC 5321.
+const MAX_LOCAL_LEN: usize = 64;
+/// The maximal length of a label of the domain, from RFC 1035.
+const MAX_LABEL_LEN: usize = 63;
+/// The characters allowed in an unquoted local part besides letters and numbers.
+const ATEXT_SPECIALS: &str = "!#$%&'*+-/=?^_`{|}~";
+
+/// The syntax an email address must follow.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EmailMode {
+    /// The address of an `<input type="email">`, following the
+    /// [valid email address](https://html.spec.whatwg.org/multipage/input.html#valid-e-mail-address)
+    /// grammar of the HTML spec: ASCII only, without quoted local parts or IP literals
+    #[default]
+    Html5,
+    /// The strict address syntax of RFC 5322, with quoted local parts and IP literals
+    Rfc5322,
+    /// Unicode local parts and internationalized domains, stored as punycode once normalized
+    Internationalized,
+}
+
+impl EmailMode {
+    /// Whether the address follows the syntax of the mode.
+    pub fn is_valid(&self, value: &str) -> bool {
+        match self {
+            EmailMode::Html5 => is_valid_html5_address(value),
+            EmailMode::Rfc5322 => is_valid_address(value, false),
+            EmailMode::Internationalized => is_valid_address(value, true),
+        }
+    }
+}
+
+/// Characters of `atext` or dots, an `@`, then labels separated by dots.
+fn is_valid_html5_address(value: &str) -> bool {
+    let Some((local, domain)) = value.split_once('@') else {
+        return false;
+    };
+    let is_local_char =
+        |c: char| c.is_ascii_alphanumeric() || c == '.' || ATEXT_SPECIALS.contains(c);
+    !local.is_empty() && local.chars().all(is_local_char) && domain.split('.').all(is_valid_label)
+}
+
+fn is_valid_address(value: &str, unicode: bool) -> bool {
+    let Some((local, domain)) = value.rsplit_once('@') else {
+        return false;
+    };
+    if unicode {
+        let Ok(domain) = idna::domain_to_ascii(domain) else {
+            return false;
+        };
+        local.len() <= MAX_LOCAL_LEN
+            && local.len() + 1 + domain.len() <= MAX_LEN
+            && is_valid_local_part(local, true)
+            && is_valid_hostname(&domain)
+    } else {
+        value.is_ascii()
+            && value.len() <= MAX_LEN
+            && local.len() <= MAX_LOCAL_LEN
+            && is_valid_local_part(local, false)
+            && (is_valid_hostname(domain) || is_valid_domain_literal(domain))
+    }
+}
+
+/// A dot-atom like `first.last` or a quoted string like `"first last"`.
+fn is_valid_local_part(local: &str, unicode: bool) -> bool {
+    if let Some(quoted) = local
+        .strip_prefix('"')
+        .and_then(|local| local.strip_suffix('"'))
+    {
+        return is_valid_quoted_string(quoted, unicode);
+    }
+    let is_atext = |c: char| c.is_ascii_alphanumeric() || ATEXT_SPECIALS.contains(c);
+    local.split('.').all(|atom| {
+        !atom.is_empty()
+            && atom
+                .chars()
+                .all(|c| is_atext(c) || unicode && !c.is_ascii())
+    })
+}
+
+fn is_valid_quoted_string(quoted: &str, unicode: bool) -> bool {
+    let is_printable =
+        |c: char| c == ' ' || c.is_ascii_graphic() || unicode && !c.is_ascii() && !c.is_control();
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(escaped) if is_printable(escaped) => {}
+                _ => return false,
+            },
+            '"' => return false,
+            c if !is_printable(c) => return false,
+            _ => {}
+        }
+    }
+    true
+}
+
+/// Labels of letters, numbers and inner hyphens, with a top-level domain that is not a number.
+fn is_valid_hostname(domain: &str) -> bool {
+    let labels: Vec<&str> = domain.split('.').collect();
+    labels.iter().all(|label| is_valid_label(label))
+        && labels
+            .last()
+            .is_some_and(|tld| !tld.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Letters, numbers and inner hyphens.
+fn is_valid_label(label: &str) -> bool {
+    !label.is_empty()
+        && label.len() <= MAX_LABEL_LEN
+        && !label.starts_with('-')
+        && !label.ends_with('-')
+        && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+/// An IP address in brackets, like `[192.168.0.1]` or `[IPv6:::1]`.
+fn is_valid_domain_literal(domain: &str) -> bool {
+    let Some(literal) = domain
+        .strip_prefix('[')
+        .and_then(|domain| domain.strip_suffix(']'))
+    else {
+        return false;
+    };
+    match literal.strip_prefix("IPv6:") {
+        Some(ipv6) => ipv6.parse::<Ipv6Addr>().is_ok(),
+        None => literal.parse::<Ipv4Addr>().is_ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn html5_follows_the_whatwg_grammar() {
+        let mode = EmailMode::Html5;
+        for valid in [
+            "john@example.com",
+            "first.last+tag@sub.example.co.uk",
+            "a@localhost",
+            "o'brien@example.com",
+            ".a..b.@example.com",
+            "a@123.com",
+        ] {
+            assert!(mode.is_valid(valid), "{valid}");
+        }
+        for invalid in [
+            "a@bücher.de",
+            "a@[1.2.3.4]",
+            "\"john doe\"@example.com",
+            "jöhn@example.com",
+            "a@-example.com",
+            "a@example-.com",
+            "a@example..com",
+            "a@b@example.com",
+            "a b@example.com",
+            "@example.com",
+            "john@",
+            "john",
+        ] {
+            assert!(!mode.is_valid(invalid), "{invalid}");
+        }
+        assert!(!mode.is_valid(&format!("a@{}.com", "x".repeat(64))));
+    }
+
+    #[test]
+    fn rfc5322_accepts_quoted_strings_and_ip_literals() {
+        let mode = EmailMode::Rfc5322;
+        for valid in [
+            "john@example.com",
+            "\"john doe\"@example.com",
+            "a@[1.2.3.4]",
+            "a@[IPv6:::1]",
+        ] {
+            assert!(mode.is_valid(valid), "{valid}");
+        }
+        for invalid in [
+            "a@bücher.de",
+            ".a@example.com",
+            "a..b@example.com",
+            "a@[1.2.3]",
+            "a@example.123",
+        ] {
+            assert!(!mode.is_valid(invalid), "{invalid}");
+        }
+        assert!(!mode.is_valid(&format!("{}@example.com", "a".repeat(65))));
+    }
+
+    #[test]
+    fn internationalized_accepts_unicode() {
+        let mode = EmailMode::Internationalized;
+        for valid in ["a@bücher.de", "jöhn@example.com", "用户@例子.中国"] {
+            assert!(mode.is_valid(valid), "{valid}");
+        }
+        assert!(!mode.is_valid("a@[1.2.3.4]"));
+    }
+}
@@ -0,0 +1,161 @@
+use super::{is_disposable_domain, EmailMode};
+use crate::validation::no_params;
+use crate::{ValidationError, Validator};
+
+/// The rules an email address must follow, and how it is normalized once typed.
+///
+/// Domains of the allow and deny lists also match their subdomains, so allowing
+/// `company.com` allows `eu.company.com`. Internationalized domains are compared
+/// in their punycode form.
+#[derive(Clone, Debug, Default)]
+pub struct EmailPolicy {
+    mode: EmailMode,
+    trim: bool,
+    lowercase: bool,
+    allowed_domains: Vec<String>,
+    denied_domains: Vec<String>,
+    reject_disposable: bool,
+}
+
+impl EmailPolicy {
+    /// A policy accepting the addresses of the HTML5 spec.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The syntax of the addresses, defaults to [`EmailMode::Html5`].
+    pub fn mode(mut self, mode: EmailMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Removes the spaces around the address once typed.
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Lowercases the address once typed.
+    ///
+    /// Most providers ignore the case of the local part, but RFC 5321 does not require it.
+    pub fn lowercase(mut self, lowercase: bool) -> Self {
+        self.lowercase = lowercase;
+        self
+    }
+
+    /// Only accepts the addresses of these domains, like `company.com`.
+    pub fn allow_domains<I, S>(mut self, domains: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.allowed_domains
+            .extend(domains.into_iter().map(|d| normalize_domain(d.as_ref())));
+        self
+    }
+
+    /// Rejects the addresses of these domains.
+    pub fn deny_domains<I, S>(mut self, domains: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.denied_domains
+            .extend(domains.into_iter().map(|d| normalize_domain(d.as_ref())));
+        self
+    }
+
+    /// Rejects the addresses of disposable email services, see [`is_disposable_domain`].
+    pub fn reject_disposable(mut self, reject: bool) -> Self {
+        self.reject_disposable = reject;
+        self
+    }
+
+    /// The address as it should be stored: trimmed and lowercased when enabled,
+    /// with a punycode domain in the internationalized mode.
+    pub fn normalize(&self, email: &str) -> String {
+        let mut email = if self.trim { email.trim() } else { email }.to_string();
+        if self.lowercase {
+            email = email.to_lowercase();
+        }
+        if self.mode == EmailMode::Internationalized {
+            if let Some((local, domain)) = email.rsplit_once('@') {
+                if let Ok(domain) = idna::domain_to_ascii(domain) {
+                    email = format!("{local}@{domain}");
+                }
+            }
+        }
+        email
+    }
+
+    fn matches(domain: &str, patterns: &[String]) -> bool {
+        patterns.iter().any(|pattern| {
+            domain == pattern
+                || domain
+                    .strip_suffix(pattern.as_str())
+                    .is_some_and(|sub| sub.ends_with('.'))
+        })
+    }
+}
+
+impl Validator for EmailPolicy {
+    fn validate(&self, value: &str) -> Result<(), ValidationError> {
+        let value = if self.trim { value.trim() } else { value };
+        if !self.mode.is_valid(value) {
+            return Err(ValidationError::from_key("email", no_params()));
+        }
+        let Some((_, domain)) = value.rsplit_once('@') else {
+            return Ok(());
+        };
+        let domain = normalize_domain(domain);
+        if !self.allowed_domains.is_empty() && !Self::matches(&domain, &self.allowed_domains) {
+            return Err(ValidationError::from_key(
+                "email.domain_not_allowed",
+                [("domains", self.allowed_domains.join(", "))],
+            ));
+        }
+        if Self::matches(&domain, &self.denied_domains) {
+            return Err(ValidationError::from_key(
+                "email.domain_denied",
+                [("domain", domain)],
+            ));
+        }
+        if self.reject_disposable && is_disposable_domain(&domain) {
+            return Err(ValidationError::from_key("email.disposable", no_params()));
+        }
+        Ok(())
+    }
+}
+
+/// The lowercase punycode form of the domain, without a leading `@` or `.`.
+fn normalize_domain(domain: &str) -> String {
+    let domain = domain.trim().trim_start_matches(['@', '.']).to_lowercase();
+    idna::domain_to_ascii(&domain).unwrap_or(domain)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_the_stored_address() {
+        let policy = EmailPolicy::new().trim(true).lowercase(true);
+        assert_eq!(policy.normalize("  John@Example.COM "), "john@example.com");
+        assert!(policy.validate("  john@example.com ").is_ok());
+
+        let policy = policy.mode(EmailMode::Internationalized);
+        assert_eq!(policy.normalize(" a@Bücher.de"), "a@xn--bcher-kva.de");
+        assert_eq!(EmailPolicy::new().normalize(" A@b.c"), " A@b.c");
+    }
+
+    #[test]
+    fn matches_domains_and_subdomains() {
+        let policy = EmailPolicy::new()
+            .allow_domains(["company.com"])
+            .deny_domains(["old.company.com"]);
+        assert!(policy.validate("a@company.com").is_ok());
+        assert!(policy.validate("a@eu.company.com").is_ok());
+        assert!(policy.validate("a@mycompany.com").is_err());
+        assert!(policy.validate("a@old.company.com").is_err());
+    }
+}
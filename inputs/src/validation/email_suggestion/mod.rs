@@ -0,0 +1,4 @@
+mod suggester;
+mod tld;
+
+pub use suggester::EmailSuggester;
@@ -0,0 +1,308 @@
+use super::tld::{is_top_level_domain, public_suffix};
+
+/// Popular email domains, suggested when a domain is one or two typos away from them.
+const DOMAINS: &[&str] = &[
+    "gmail.com",
+    "googlemail.com",
+    "yahoo.com",
+    "yahoo.fr",
+    "yahoo.de",
+    "yahoo.co.uk",
+    "hotmail.com",
+    "hotmail.fr",
+    "hotmail.de",
+    "hotmail.co.uk",
+    "outlook.com",
+    "outlook.fr",
+    "outlook.de",
+    "live.com",
+    "live.fr",
+    "msn.com",
+    "icloud.com",
+    "me.com",
+    "mac.com",
+    "aol.com",
+    "protonmail.com",
+    "proton.me",
+    "gmx.de",
+    "gmx.net",
+    "gmx.fr",
+    "web.de",
+    "t-online.de",
+    "orange.fr",
+    "wanadoo.fr",
+    "free.fr",
+    "sfr.fr",
+    "laposte.net",
+    "yandex.com",
+    "yandex.ru",
+    "mail.ru",
+    "mail.com",
+    "zoho.com",
+    "comcast.net",
+    "verizon.net",
+    "att.net",
+    "sbcglobal.net",
+];
+/// The names of the popular providers, corrected whatever the top-level domain is.
+const SECOND_LEVEL_DOMAINS: &[&str] = &[
+    "gmail",
+    "googlemail",
+    "yahoo",
+    "hotmail",
+    "outlook",
+    "live",
+    "icloud",
+    "protonmail",
+    "gmx",
+    "orange",
+    "wanadoo",
+    "laposte",
+    "yandex",
+];
+/// The top-level domains suggested for the ones that do not exist.
+const TOP_LEVEL_DOMAINS: &[&str] = &[
+    "com", "net", "org", "edu", "gov", "io", "co", "co.uk", "uk", "fr", "de", "ch", "be", "ca",
+    "es", "it", "nl", "at", "eu", "info", "biz", "me",
+];
+/// Shorter names are too close to real domains, `hive` or `mail`, to be corrected.
+const MIN_NAME_LEN: usize = 5;
+
+/// Suggests corrections of mistyped email domains, like `gmail.com` for `gmial.com`.
+///
+/// The domain is compared to popular domains, then its name and its top-level domain
+/// are compared separately, so `yahoo.fr` is suggested for `yaho.fr` and `company.com`
+/// for `company.con`. Short domains only tolerate one typo.
+///
+/// A top-level domain that exists, like `ru` or `io`, is never corrected, and only the name
+/// right under the public suffix is, so `mail.company.com` is left as typed.
+#[derive(Clone, Debug)]
+pub struct EmailSuggester {
+    domains: Vec<String>,
+    second_level_domains: Vec<String>,
+    top_level_domains: Vec<String>,
+    max_distance: usize,
+}
+
+impl Default for EmailSuggester {
+    fn default() -> Self {
+        let owned = |list: &[&str]| list.iter().map(|s| s.to_string()).collect();
+        EmailSuggester {
+            domains: owned(DOMAINS),
+            second_level_domains: owned(SECOND_LEVEL_DOMAINS),
+            top_level_domains: owned(TOP_LEVEL_DOMAINS),
+            max_distance: 2,
+        }
+    }
+}
+
+impl EmailSuggester {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the popular domains, like `gmail.com`.
+    pub fn domains<I, S>(mut self, domains: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.domains = domains.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Adds popular domains, like the domain of your company.
+    pub fn with_domains<I, S>(mut self, domains: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.domains.extend(domains.into_iter().map(Into::into));
+        self
+    }
+
+    /// Replaces the names of the popular providers, like `gmail`.
+    pub fn second_level_domains<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.second_level_domains = names.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Replaces the top-level domains, like `com`.
+    pub fn top_level_domains<I, S>(mut self, tlds: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.top_level_domains = tlds.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// The maximal number of typos corrected, defaults to 2.
+    pub fn max_distance(mut self, distance: usize) -> Self {
+        self.max_distance = distance;
+        self
+    }
+
+    /// The corrected email, or `None` if its domain does not look mistyped.
+    pub fn suggest(&self, email: &str) -> Option<String> {
+        let (local, domain) = email.trim().rsplit_once('@')?;
+        let domain = domain.to_lowercase();
+        if local.is_empty() || domain.is_empty() || self.domains.contains(&domain) {
+            return None;
+        }
+        let suffix = public_suffix(&domain);
+        let tld = suffix.rsplit('.').next().unwrap_or(suffix);
+        let real_tld = is_top_level_domain(tld);
+
+        let popular = self
+            .domains
+            .iter()
+            .filter(|popular| !real_tld || public_suffix(popular) == suffix);
+        if let Some(closest) = self.closest(&domain, popular, self.max_distance) {
+            return Some(format!("{local}@{closest}"));
+        }
+
+        let name = domain[..domain.len() - suffix.len()].strip_suffix('.')?;
+        let name = if name.contains('.') || name.chars().count() < MIN_NAME_LEN {
+            name
+        } else {
+            let names = &self.second_level_domains;
+            self.closest(name, names, self.max_distance).unwrap_or(name)
+        };
+        let suffix = if real_tld {
+            suffix
+        } else {
+            let tlds = &self.top_level_domains;
+            self.closest(suffix, tlds, 1).unwrap_or(suffix)
+        };
+        let suggestion = format!("{name}.{suffix}");
+        (suggestion != domain).then(|| format!("{local}@{suggestion}"))
+    }
+
+    /// The candidate the closest to the value, if it is close enough for the length of the value
+    /// and the value is not a candidate itself.
+    fn closest<'a>(
+        &self,
+        value: &str,
+        candidates: impl IntoIterator<Item = &'a String>,
+        max: usize,
+    ) -> Option<&'a str> {
+        let max = max.min(value.chars().count() / 4).max(1);
+        let mut closest = None;
+        for candidate in candidates {
+            let distance = edit_distance(value, candidate);
+            if distance == 0 {
+                return None;
+            }
+            if distance <= max && closest.map_or(true, |(closest, _)| distance < closest) {
+                closest = Some((distance, candidate.as_str()));
+            }
+        }
+        closest.map(|(_, candidate)| candidate)
+    }
+}
+
+/// The number of insertions, deletions, substitutions and transpositions of adjacent
+/// characters turning `a` into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+    distances[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn suggest(email: &str) -> Option<String> {
+        EmailSuggester::new().suggest(email)
+    }
+
+    #[test]
+    fn corrects_popular_domains() {
+        assert_eq!(suggest("john@gmial.com").as_deref(), Some("john@gmail.com"));
+        assert_eq!(suggest("john@hotmial.con").as_deref(), Some("john@hotmail.com"));
+        assert_eq!(suggest("john@yaho.fr").as_deref(), Some("john@yahoo.fr"));
+        assert_eq!(suggest("john@GMAIL.CMO").as_deref(), Some("john@gmail.com"));
+    }
+
+    #[test]
+    fn corrects_top_level_domains_that_do_not_exist() {
+        assert_eq!(suggest("john@company.con").as_deref(), Some("john@company.com"));
+        assert_eq!(suggest("john@company.co.uj").as_deref(), Some("john@company.co.uk"));
+    }
+
+    #[test]
+    fn corrects_provider_names_under_any_suffix() {
+        assert_eq!(suggest("john@gmial.ch").as_deref(), Some("john@gmail.ch"));
+        assert_eq!(suggest("john@hotmial.co.jp").as_deref(), Some("john@hotmail.co.jp"));
+    }
+
+    #[test]
+    fn keeps_valid_addresses() {
+        for email in [
+            "john@gmail.com",
+            "a@mail.ru",
+            "a@yandex.ru",
+            "john@mail.company.com",
+            "a@yahoo.co.jp",
+            "a@hive.io",
+            "a@live.io",
+            "a@company.de",
+            "a@sub.domain.co.uk",
+        ] {
+            assert_eq!(suggest(email), None, "{email}");
+        }
+    }
+
+    #[test]
+    fn ignores_incomplete_addresses() {
+        for email in ["", "john", "john@", "@gmial.com", "john@localhost"] {
+            assert_eq!(suggest(email), None, "{email}");
+        }
+    }
+
+    #[test]
+    fn uses_the_configured_domains() {
+        let suggester = EmailSuggester::new().with_domains(["acme-corp.com"]);
+        assert_eq!(
+            suggester.suggest("jane@acme-crop.com").as_deref(),
+            Some("jane@acme-corp.com")
+        );
+        let suggester = EmailSuggester::new().domains(["acme-corp.com"]);
+        assert_eq!(suggester.suggest("jane@gmial.com").as_deref(), Some("jane@gmail.com"));
+        let suggester = suggester.second_level_domains(Vec::<String>::new());
+        assert_eq!(suggester.suggest("jane@gmial.com"), None);
+    }
+
+    #[test]
+    fn counts_transpositions_as_one_edit() {
+        assert_eq!(edit_distance("gmail", "gmial"), 1);
+        assert_eq!(edit_distance("hotmail", "hotmial"), 1);
+        assert_eq!(edit_distance("com", "con"), 1);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+}
@@ -0,0 +1,55 @@
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+/// The country code top-level domains, and the generic ones in common use.
+const TOP_LEVEL_DOMAINS: &str = include_str!("top_level_domains.txt");
+/// The public suffixes of two labels under which the providers register their domains.
+const SECOND_LEVEL_SUFFIXES: &[&str] = &[
+    "co.uk", "org.uk", "me.uk", "ac.uk", "co.jp", "ne.jp", "or.jp", "com.au", "net.au", "co.nz",
+    "com.br", "com.mx", "com.ar", "co.in", "co.za", "co.kr", "com.cn", "com.tw", "com.hk",
+    "com.sg", "com.tr", "com.ua", "co.il", "co.id", "com.my", "com.ph",
+];
+
+/// Whether the label is a real top-level domain, like `com` or `ru`.
+pub(crate) fn is_top_level_domain(label: &str) -> bool {
+    static DOMAINS: OnceLock<HashSet<&'static str>> = OnceLock::new();
+    DOMAINS
+        .get_or_init(|| TOP_LEVEL_DOMAINS.lines().collect())
+        .contains(label)
+}
+
+/// The public suffix of the domain, like `com` for `gmail.com` and `co.uk` for `yahoo.co.uk`.
+pub(crate) fn public_suffix(domain: &str) -> &str {
+    let labels: Vec<&str> = domain.rsplitn(3, '.').collect();
+    if let [tld, second, _] = labels[..] {
+        let suffix = &domain[domain.len() - tld.len() - second.len() - 1..];
+        if SECOND_LEVEL_SUFFIXES.contains(&suffix) {
+            return suffix;
+        }
+    }
+    labels[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn knows_country_and_generic_domains() {
+        for tld in ["com", "org", "io", "ru", "jp", "om", "uk", "dev"] {
+            assert!(is_top_level_domain(tld), "{tld}");
+        }
+        for tld in ["con", "cmo", "ocm", "frr", "uj"] {
+            assert!(!is_top_level_domain(tld), "{tld}");
+        }
+    }
+
+    #[test]
+    fn splits_public_suffixes() {
+        assert_eq!(public_suffix("gmail.com"), "com");
+        assert_eq!(public_suffix("yahoo.co.uk"), "co.uk");
+        assert_eq!(public_suffix("mail.company.com"), "com");
+        assert_eq!(public_suffix("co.uk"), "uk");
+        assert_eq!(public_suffix("localhost"), "localhost");
+    }
+}
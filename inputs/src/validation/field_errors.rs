@@ -0,0 +1,116 @@
+use crate::i18n::translate;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Error messages keyed by the `name` of the inputs they belong to.
+///
+/// A server function can return them to reject a submission, and
+/// [`FormContext::set_errors`](crate::FormContext::set_errors) displays them on the matching inputs.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldErrors {
+    errors: BTreeMap<String, Vec<String>>,
+}
+
+impl FieldErrors {
+    pub fn new() -> Self {
+        FieldErrors::default()
+    }
+
+    /// Adds an error message to the field.
+    pub fn add(&mut self, field: impl Into<String>, message: impl Into<String>) {
+        self.errors
+            .entry(field.into())
+            .or_default()
+            .push(message.into());
+    }
+
+    /// Adds an error message to the field, see [`FieldErrors::add`].
+    pub fn with(mut self, field: impl Into<String>, message: impl Into<String>) -> Self {
+        self.add(field, message);
+        self
+    }
+
+    /// The error messages of the field, in the order they were added.
+    pub fn get(&self, field: &str) -> &[String] {
+        self.errors.get(field).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// The fields and their error messages.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[String])> {
+        self.errors
+            .iter()
+            .map(|(field, messages)| (field.as_str(), messages.as_slice()))
+    }
+}
+
+/// Keeps the message of each error, or the translation of its code with its parameters,
+/// like `length`, or the code itself when it has no translation.
+impl From<validator::ValidationErrors> for FieldErrors {
+    fn from(errors: validator::ValidationErrors) -> Self {
+        let mut field_errors = FieldErrors::new();
+        for (field, errors) in errors.field_errors() {
+            for error in errors {
+                let message = error.message.as_ref().map(|m| m.to_string());
+                let message = message.unwrap_or_else(|| {
+                    let params: Vec<(&str, String)> = error
+                        .params
+                        .iter()
+                        .map(|(name, value)| {
+                            let value = value
+                                .as_str()
+                                .map_or_else(|| value.to_string(), String::from);
+                            (name.as_ref(), value)
+                        })
+                        .collect();
+                    translate(&error.code, &params)
+                });
+                field_errors.add(field, message);
+            }
+        }
+        field_errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+
+    #[test]
+    fn keeps_every_message() {
+        let errors = FieldErrors::new()
+            .with("email", "Already taken")
+            .with("email", "Blocked domain")
+            .with("name", "Too short");
+        assert_eq!(errors.get("email"), ["Already taken", "Blocked domain"]);
+        assert_eq!(errors.get("name"), ["Too short"]);
+        assert!(errors.get("age").is_empty());
+        assert_eq!(errors.iter().count(), 2);
+    }
+
+    #[test]
+    fn converts_validator_errors() {
+        let mut errors = validator::ValidationErrors::new();
+        let mut length = validator::ValidationError::new("length");
+        length.add_param(Cow::from("min"), &3);
+        errors.add("username", length);
+        let mut custom = validator::ValidationError::new("taken");
+        custom.message = Some(Cow::from("Already taken"));
+        errors.add("username", custom);
+        errors.add("email", validator::ValidationError::new("email"));
+        errors.add("code", validator::ValidationError::new("not_a_key"));
+
+        let errors = FieldErrors::from(errors);
+        assert_eq!(errors.get("username")[1], "Already taken");
+        assert_eq!(errors.get("email"), [translate("email", &[])]);
+        assert_eq!(errors.get("code"), ["not_a_key"]);
+        assert_eq!(
+            errors.get("username")[0],
+            translate("length", &[("min", String::from("3"))])
+        );
+    }
+}
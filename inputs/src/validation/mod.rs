@@ -0,0 +1,29 @@
+mod async_validator;
+mod breached;
+mod cross_field;
+mod email_policy;
+mod email_suggestion;
+mod field_errors;
+mod mode;
+mod password_generator;
+mod password_policy;
+mod rules;
+mod strength;
+mod validator;
+
+pub use async_validator::{AnyAsyncValidator, AsyncValidator, ValidationFuture};
+pub use breached::{not_breached, BreachFuture, BreachedPasswordSource, NotBreached};
+pub use breached::{EmbeddedPasswordList, RangeApiSource};
+pub use cross_field::{cross_field, matches, required_unless, CrossField, Matches};
+pub use email_policy::{is_disposable_domain, EmailMode, EmailPolicy};
+pub use email_suggestion::EmailSuggester;
+pub use field_errors::FieldErrors;
+pub use mode::ValidationMode;
+pub use password_generator::PasswordGenerator;
+pub(crate) use password_policy::SPECIAL_CHARACTERS;
+pub use password_policy::{PasswordPolicy, PasswordRequirement, RequirementCheck};
+pub use rules::{custom, email, max_len, min_len, one_of, regex};
+pub use rules::{Custom, Email, MaxLen, MinLen, OneOf, Pattern};
+pub use strength::{estimate_strength, PasswordStrength, StrengthEstimate};
+pub(crate) use validator::no_params;
+pub use validator::{And, AnyValidator, Or, ValidationError, Validator, WithMessage};
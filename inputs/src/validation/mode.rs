@@ -0,0 +1,22 @@
+/// When the validation result of an input is displayed.
+///
+/// The validity of an input is always tracked, so a [`FormContext`](crate::FormContext)
+/// knows whether it can be submitted whatever the mode.
+///
+/// The mode of an input is its `mode` prop, or the `validation_mode` of the surrounding
+/// [`FormBox`](crate::FormBox), or the `validation_mode` of the [`GlobalTheme`](crate::GlobalTheme).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ValidationMode {
+    /// The result is displayed as soon as the value changes, and when the input loses the focus.
+    #[default]
+    OnInput,
+    /// The result is displayed when the input loses the focus, and hidden while the value changes.
+    OnBlur,
+    /// The result is displayed once the form is submitted, then updated as the value changes.
+    ///
+    /// An input outside of a [`FormBox`](crate::FormBox) is never submitted, so it falls back
+    /// to [`OnBlur`](ValidationMode::OnBlur).
+    OnSubmit,
+    /// The result is displayed once the input lost the focus, then updated as the value changes.
+    EagerAfterTouched,
+}
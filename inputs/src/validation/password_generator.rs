@@ -0,0 +1,201 @@
+use crate::{PasswordPolicy, Validator};
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use std::cell::RefCell;
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const NUMBERS: &str = "0123456789";
+/// Characters easily mistaken for one another when read or typed.
+const AMBIGUOUS: &str = "Il1|O0o`'\"";
+/// The number of passwords generated before giving up on a policy that cannot be satisfied.
+const MAX_ATTEMPTS: usize = 100;
+
+/// Generates random passwords satisfying a [`PasswordPolicy`].
+///
+/// Passwords are drawn from ChaCha20 seeded by the random source of the platform, which is
+/// `crypto.getRandomValues` in the browser. A generator built with [`PasswordGenerator::seed`]
+/// always generates the same passwords, which is useful in tests.
+#[derive(Clone)]
+pub struct PasswordGenerator {
+    length: usize,
+    exclude_ambiguous: bool,
+    policy: PasswordPolicy,
+    rng: Rc<RefCell<ChaCha20Rng>>,
+}
+
+impl Default for PasswordGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PasswordGenerator {
+    /// The default length of the generated passwords.
+    pub const DEFAULT_LENGTH: usize = 16;
+
+    /// Generates passwords of 16 characters, with at least one lowercase letter, one uppercase
+    /// letter, one number and one special character.
+    pub fn new() -> Self {
+        Self::for_policy(
+            PasswordPolicy::new()
+                .lowercase(1)
+                .uppercase(1)
+                .numbers(1)
+                .special(1),
+        )
+    }
+
+    /// Generates passwords satisfying the policy, at least 16 characters long if it allows it.
+    pub fn for_policy(policy: PasswordPolicy) -> Self {
+        PasswordGenerator {
+            length: Self::DEFAULT_LENGTH,
+            exclude_ambiguous: true,
+            policy,
+            rng: Rc::new(RefCell::new(ChaCha20Rng::from_entropy())),
+        }
+    }
+
+    /// Sets the length of the generated passwords, within the lengths allowed by the policy.
+    pub fn length(mut self, length: usize) -> Self {
+        self.length = length;
+        self
+    }
+
+    /// Whether characters like `l`, `1` and `I` are left out, defaults to `true`.
+    pub fn exclude_ambiguous(mut self, exclude: bool) -> Self {
+        self.exclude_ambiguous = exclude;
+        self
+    }
+
+    /// Generates the same sequence of passwords for the same seed.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.rng = Rc::new(RefCell::new(ChaCha20Rng::seed_from_u64(seed)));
+        self
+    }
+
+    /// Generates a password, satisfying the policy unless it cannot be satisfied.
+    pub fn generate(&self) -> String {
+        let mut password = self.candidate();
+        for _ in 1..MAX_ATTEMPTS {
+            if self.policy.validate(&password).is_ok() {
+                break;
+            }
+            password = self.candidate();
+        }
+        password
+    }
+
+    fn candidate(&self) -> String {
+        let policy = &self.policy;
+        let pool = |characters: &str| -> Vec<char> {
+            characters
+                .chars()
+                .filter(|c| !self.exclude_ambiguous || !AMBIGUOUS.contains(*c))
+                .collect()
+        };
+        let classes = [
+            (pool(LOWERCASE), policy.lowercase),
+            (pool(UPPERCASE), policy.uppercase),
+            (pool(NUMBERS), policy.numbers),
+            (pool(&policy.special_characters), policy.special),
+        ];
+        let required: usize = classes.iter().map(|(_, count)| count).sum();
+        let mut length = self.length.max(policy.min_length).max(required);
+        if let Some(max) = policy.max_length {
+            length = length.min(max);
+        }
+        let all: Vec<char> = classes.iter().flat_map(|(pool, _)| pool.clone()).collect();
+
+        let mut rng = self.rng.borrow_mut();
+        let mut password = Vec::with_capacity(length);
+        for (pool, count) in classes.iter().filter(|(pool, _)| !pool.is_empty()) {
+            for _ in 0..*count {
+                password.push(pool[rng.gen_range(0..pool.len())]);
+            }
+        }
+        while password.len() < length {
+            password.push(all[rng.gen_range(0..all.len())]);
+        }
+        password.shuffle(&mut *rng);
+        password.into_iter().take(length).collect()
+    }
+}
+
+impl Debug for PasswordGenerator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PasswordGenerator")
+            .field("length", &self.length)
+            .field("exclude_ambiguous", &self.exclude_ambiguous)
+            .field("policy", &self.policy)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PasswordValidationLevel;
+
+    #[test]
+    fn a_seed_generates_the_same_passwords() {
+        let first = PasswordGenerator::new().seed(42);
+        let second = PasswordGenerator::new().seed(42);
+        for _ in 0..10 {
+            assert_eq!(first.generate(), second.generate());
+        }
+        // The same on every run and every platform, as ChaCha20 is portable.
+        assert_eq!(PasswordGenerator::new().seed(42).generate(), "E5K$nwq_fC8ihY:3");
+        let other = PasswordGenerator::new().seed(43);
+        assert_ne!(
+            PasswordGenerator::new().seed(42).generate(),
+            other.generate()
+        );
+    }
+
+    #[test]
+    fn satisfies_the_validation_levels() {
+        for level in [
+            PasswordValidationLevel::Weak,
+            PasswordValidationLevel::Medium,
+            PasswordValidationLevel::Strong,
+        ] {
+            let policy = level.policy().unwrap();
+            let generator = PasswordGenerator::for_policy(policy.clone()).seed(7);
+            for _ in 0..200 {
+                let password = generator.generate();
+                assert!(policy.validate(&password).is_ok(), "{password}");
+                assert!(level.validate(&password).is_ok(), "{password}");
+                assert_eq!(password.chars().count(), PasswordGenerator::DEFAULT_LENGTH);
+                assert!(
+                    !password.chars().any(|c| AMBIGUOUS.contains(c)),
+                    "{password}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn follows_the_lengths_of_the_policy() {
+        let policy = PasswordPolicy::new().min_length(20).numbers(2);
+        let password = PasswordGenerator::for_policy(policy).seed(1).generate();
+        assert_eq!(password.chars().count(), 20);
+
+        let policy = PasswordPolicy::new().max_length(10).lowercase(1);
+        let password = PasswordGenerator::for_policy(policy).seed(1).generate();
+        assert_eq!(password.chars().count(), 10);
+    }
+
+    #[test]
+    fn keeps_ambiguous_characters_when_asked() {
+        let generator = PasswordGenerator::new()
+            .exclude_ambiguous(false)
+            .length(64)
+            .seed(3);
+        let found = (0..20).any(|_| generator.generate().chars().any(|c| AMBIGUOUS.contains(c)));
+        assert!(found);
+    }
+}
@@ -0,0 +1,388 @@
+use crate::{ValidationError, Validator};
+use leptos::{MaybeSignal, SignalGet};
+use std::collections::HashSet;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The special characters accepted by default.
+pub(crate) const SPECIAL_CHARACTERS: &str = "!'\"@#$%-_~+=?,./:;`&^|*";
+/// Forbidden values shorter than this are ignored, so a short username does not forbid letters.
+const MIN_FORBIDDEN_LEN: usize = 3;
+
+/// A single rule of a [`PasswordPolicy`], displayed in the checklist of
+/// [`InputPassword`](crate::InputPassword).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PasswordRequirement {
+    /// At least the given number of characters
+    MinLength(usize),
+    /// At most the given number of characters
+    MaxLength(usize),
+    /// At least the given number of lowercase letters
+    Lowercase(usize),
+    /// At least the given number of uppercase letters
+    Uppercase(usize),
+    /// At least the given number of numbers
+    Number(usize),
+    /// At least the given number of the special characters
+    SpecialCharacter(usize, String),
+    /// The same character at most the given number of times in a row
+    MaxRepeated(usize),
+    /// Does not contain the value, ignoring the case
+    NotContaining(String),
+    /// Is not in the deny-list of the policy
+    NotDenied,
+}
+
+impl PasswordRequirement {
+    /// The description of the requirement in the current locale.
+    pub fn message(&self) -> String {
+        self.error().localized()
+    }
+
+    fn error(&self) -> ValidationError {
+        let counted = |key: &str, count: usize, params: Vec<(&str, String)>| {
+            let key = if count == 1 {
+                key.to_string()
+            } else {
+                format!("{key}_count")
+            };
+            let mut params = params;
+            params.push(("count", count.to_string()));
+            ValidationError::from_key(key, params)
+        };
+        match self {
+            PasswordRequirement::MinLength(min) => {
+                ValidationError::from_key("password.min_len", [("min", min)])
+            }
+            PasswordRequirement::MaxLength(max) => {
+                ValidationError::from_key("password.max_len", [("max", max)])
+            }
+            PasswordRequirement::Lowercase(count) => {
+                counted("password.lowercase", *count, Vec::new())
+            }
+            PasswordRequirement::Uppercase(count) => {
+                counted("password.uppercase", *count, Vec::new())
+            }
+            PasswordRequirement::Number(count) => counted("password.number", *count, Vec::new()),
+            PasswordRequirement::SpecialCharacter(count, characters) => counted(
+                "password.special",
+                *count,
+                vec![("special", characters.clone())],
+            ),
+            PasswordRequirement::MaxRepeated(max) => {
+                ValidationError::from_key("password.max_repeated", [("max", max)])
+            }
+            PasswordRequirement::NotContaining(value) => {
+                ValidationError::from_key("password.not_containing", [("value", value)])
+            }
+            PasswordRequirement::NotDenied => {
+                ValidationError::from_key("password.denied", super::no_params())
+            }
+        }
+    }
+}
+
+/// Whether a requirement is met by the current password.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RequirementCheck {
+    pub requirement: PasswordRequirement,
+    pub met: bool,
+}
+
+/// The rules a password must follow.
+///
+/// Lengths are counted in graphemes, so a character made of several code points is
+/// counted once. Forbidden values are read when the password is validated, so an input
+/// using the policy is validated again when the username or the email changes.
+#[derive(Clone, Debug)]
+pub struct PasswordPolicy {
+    pub(crate) min_length: usize,
+    pub(crate) max_length: Option<usize>,
+    pub(crate) lowercase: usize,
+    pub(crate) uppercase: usize,
+    pub(crate) numbers: usize,
+    pub(crate) special: usize,
+    pub(crate) special_characters: String,
+    max_repeated: Option<usize>,
+    forbidden: Vec<MaybeSignal<String>>,
+    deny_list: HashSet<String>,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        PasswordPolicy {
+            min_length: 0,
+            max_length: None,
+            lowercase: 0,
+            uppercase: 0,
+            numbers: 0,
+            special: 0,
+            special_characters: SPECIAL_CHARACTERS.to_string(),
+            max_repeated: None,
+            forbidden: Vec::new(),
+            deny_list: HashSet::new(),
+        }
+    }
+}
+
+impl PasswordPolicy {
+    /// A policy accepting any password.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn min_length(mut self, min: usize) -> Self {
+        self.min_length = min;
+        self
+    }
+
+    pub fn max_length(mut self, max: usize) -> Self {
+        self.max_length = Some(max);
+        self
+    }
+
+    /// Requires at least `count` lowercase letters.
+    pub fn lowercase(mut self, count: usize) -> Self {
+        self.lowercase = count;
+        self
+    }
+
+    /// Requires at least `count` uppercase letters.
+    pub fn uppercase(mut self, count: usize) -> Self {
+        self.uppercase = count;
+        self
+    }
+
+    /// Requires at least `count` numbers.
+    pub fn numbers(mut self, count: usize) -> Self {
+        self.numbers = count;
+        self
+    }
+
+    /// Requires at least `count` special characters.
+    pub fn special(mut self, count: usize) -> Self {
+        self.special = count;
+        self
+    }
+
+    /// Replaces the special characters, which default to the common ASCII punctuation.
+    pub fn special_characters(mut self, characters: impl Into<String>) -> Self {
+        self.special_characters = characters.into();
+        self
+    }
+
+    /// Rejects the passwords with the same character more than `max` times in a row.
+    pub fn max_repeated(mut self, max: usize) -> Self {
+        self.max_repeated = Some(max);
+        self
+    }
+
+    /// Rejects the passwords containing the value, like the username or the email.
+    pub fn forbid(mut self, value: impl Into<MaybeSignal<String>>) -> Self {
+        self.forbidden.push(value.into());
+        self
+    }
+
+    /// Rejects the passwords of the list, ignoring the case.
+    pub fn deny<I, S>(mut self, passwords: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.deny_list
+            .extend(passwords.into_iter().map(|p| p.as_ref().to_lowercase()));
+        self
+    }
+
+    /// The requirements of the policy, with the current forbidden values.
+    pub fn requirements(&self) -> Vec<PasswordRequirement> {
+        let mut requirements = Vec::new();
+        if self.min_length > 0 {
+            requirements.push(PasswordRequirement::MinLength(self.min_length));
+        }
+        if let Some(max) = self.max_length {
+            requirements.push(PasswordRequirement::MaxLength(max));
+        }
+        if self.lowercase > 0 {
+            requirements.push(PasswordRequirement::Lowercase(self.lowercase));
+        }
+        if self.uppercase > 0 {
+            requirements.push(PasswordRequirement::Uppercase(self.uppercase));
+        }
+        if self.numbers > 0 {
+            requirements.push(PasswordRequirement::Number(self.numbers));
+        }
+        if self.special > 0 {
+            requirements.push(PasswordRequirement::SpecialCharacter(
+                self.special,
+                self.special_characters.clone(),
+            ));
+        }
+        if let Some(max) = self.max_repeated {
+            requirements.push(PasswordRequirement::MaxRepeated(max));
+        }
+        for value in &self.forbidden {
+            let value = value.get();
+            if value.graphemes(true).count() >= MIN_FORBIDDEN_LEN {
+                requirements.push(PasswordRequirement::NotContaining(value));
+            }
+        }
+        if !self.deny_list.is_empty() {
+            requirements.push(PasswordRequirement::NotDenied);
+        }
+        requirements
+    }
+
+    /// Checks each requirement of the policy against the password.
+    pub fn check(&self, password: &str) -> Vec<RequirementCheck> {
+        self.requirements()
+            .into_iter()
+            .map(|requirement| RequirementCheck {
+                met: self.is_met(&requirement, password),
+                requirement,
+            })
+            .collect()
+    }
+
+    /// The requirements the password does not meet.
+    pub fn failed(&self, password: &str) -> Vec<PasswordRequirement> {
+        self.check(password)
+            .into_iter()
+            .filter(|check| !check.met)
+            .map(|check| check.requirement)
+            .collect()
+    }
+
+    fn is_met(&self, requirement: &PasswordRequirement, password: &str) -> bool {
+        let count = |f: &dyn Fn(char) -> bool| password.chars().filter(|c| f(*c)).count();
+        match requirement {
+            PasswordRequirement::MinLength(min) => password.graphemes(true).count() >= *min,
+            PasswordRequirement::MaxLength(max) => password.graphemes(true).count() <= *max,
+            PasswordRequirement::Lowercase(min) => count(&|c| c.is_lowercase()) >= *min,
+            PasswordRequirement::Uppercase(min) => count(&|c| c.is_uppercase()) >= *min,
+            PasswordRequirement::Number(min) => count(&|c| c.is_numeric()) >= *min,
+            PasswordRequirement::SpecialCharacter(min, characters) => {
+                count(&|c| characters.contains(c)) >= *min
+            }
+            PasswordRequirement::MaxRepeated(max) => {
+                let graphemes: Vec<&str> = password.graphemes(true).collect();
+                graphemes
+                    .chunk_by(|a, b| a == b)
+                    .all(|run| run.len() <= *max)
+            }
+            PasswordRequirement::NotContaining(value) => {
+                !password.to_lowercase().contains(&value.to_lowercase())
+            }
+            PasswordRequirement::NotDenied => !self.deny_list.contains(&password.to_lowercase()),
+        }
+    }
+}
+
+/// Rejects the password with the error of the first requirement it does not meet.
+impl Validator for PasswordPolicy {
+    fn validate(&self, value: &str) -> Result<(), ValidationError> {
+        match self.failed(value).first() {
+            Some(requirement) => Err(requirement.error()),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::{create_runtime, create_rw_signal, SignalSet};
+
+    fn met(policy: &PasswordPolicy, password: &str) -> bool {
+        policy.failed(password).is_empty()
+    }
+
+    #[test]
+    fn lengths_count_graphemes() {
+        let policy = PasswordPolicy::new().min_length(4).max_length(4);
+        // A family emoji is 7 code points and 25 bytes, a flag 2 code points.
+        assert!(met(&policy, "ab👨‍👩‍👧‍👦🇫🇷"));
+        // "e" followed by a combining acute accent is a single grapheme.
+        assert!(met(&policy, "cafe\u{301}"));
+        assert!(!met(&policy, "cafe\u{301}s"));
+        assert!(!met(&policy, "👨‍👩‍👧‍👦"));
+        assert_eq!(
+            PasswordPolicy::new().min_length(2).failed("é"),
+            [PasswordRequirement::MinLength(2)]
+        );
+    }
+
+    #[test]
+    fn max_repeated() {
+        let policy = PasswordPolicy::new().max_repeated(2);
+        assert!(met(&policy, "aabbaa"));
+        assert!(!met(&policy, "abbbc"));
+        assert!(met(&policy, "AAa"));
+        assert!(!met(&policy, "🔥🔥🔥"));
+        // Combining marks make distinct graphemes.
+        assert!(met(&policy, "ee\u{301}e"));
+    }
+
+    #[test]
+    fn forbidden_values_from_the_min_length() {
+        let policy = PasswordPolicy::new()
+            .forbid(String::from("Alice"))
+            .forbid(String::from("al"));
+        assert_eq!(
+            policy.requirements(),
+            [PasswordRequirement::NotContaining(String::from("Alice"))]
+        );
+        assert!(!met(&policy, "my-aLiCe-99"));
+        assert!(met(&policy, "algebra"));
+        let policy = PasswordPolicy::new().forbid(String::from("bob"));
+        assert!(!met(&policy, "xBOBx"));
+    }
+
+    #[test]
+    fn forbidden_values_are_read_on_validation() {
+        let runtime = create_runtime();
+        let username = create_rw_signal(String::new());
+        let policy = PasswordPolicy::new().forbid(username);
+        assert!(policy.requirements().is_empty());
+        username.set(String::from("carol"));
+        assert!(policy.validate("carol123").is_err());
+        runtime.dispose();
+    }
+
+    #[test]
+    fn deny_list_ignores_the_case() {
+        let policy = PasswordPolicy::new().deny(["Password1", "qwerty"]);
+        assert!(!met(&policy, "password1"));
+        assert!(!met(&policy, "QWERTY"));
+        assert!(met(&policy, "qwerty1"));
+        assert!(!PasswordPolicy::new()
+            .requirements()
+            .contains(&PasswordRequirement::NotDenied));
+    }
+
+    #[test]
+    fn custom_special_characters() {
+        let policy = PasswordPolicy::new().special(2);
+        assert!(met(&policy, "a!b?"));
+        assert!(!met(&policy, "a!b€"));
+        let policy = policy.special_characters("€§");
+        assert!(met(&policy, "a€b§"));
+        assert!(!met(&policy, "a!b?"));
+        assert_eq!(
+            policy.requirements(),
+            [PasswordRequirement::SpecialCharacter(2, String::from("€§"))]
+        );
+    }
+
+    #[test]
+    fn counts_characters_of_each_kind() {
+        let policy = PasswordPolicy::new().lowercase(2).uppercase(1).numbers(1);
+        assert!(met(&policy, "abC1"));
+        assert_eq!(
+            policy.failed("ABc"),
+            [
+                PasswordRequirement::Lowercase(2),
+                PasswordRequirement::Number(1)
+            ]
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use crate::validation::no_params;
+use crate::validation::{ValidationError, Validator};
+use regex::Regex;
+use validator::ValidateEmail;
+
+/// The value must contain at least `len` characters, see [`min_len`].
+#[derive(Clone, Debug)]
+pub struct MinLen(usize);
+
+/// The value must contain at least `len` characters.
+pub fn min_len(len: usize) -> MinLen {
+    MinLen(len)
+}
+
+impl Validator for MinLen {
+    fn validate(&self, value: &str) -> Result<(), ValidationError> {
+        if value.chars().count() < self.0 {
+            return Err(ValidationError::from_key("min_len", [("min", self.0)]));
+        }
+        Ok(())
+    }
+}
+
+/// The value must contain at most `len` characters, see [`max_len`].
+#[derive(Clone, Debug)]
+pub struct MaxLen(usize);
+
+/// The value must contain at most `len` characters.
+pub fn max_len(len: usize) -> MaxLen {
+    MaxLen(len)
+}
+
+impl Validator for MaxLen {
+    fn validate(&self, value: &str) -> Result<(), ValidationError> {
+        if value.chars().count() > self.0 {
+            return Err(ValidationError::from_key("max_len", [("max", self.0)]));
+        }
+        Ok(())
+    }
+}
+
+/// The value must match a regular expression, see [`regex`].
+#[derive(Clone, Debug)]
+pub struct Pattern(Regex);
+
+/// The value must match the regular expression.
+///
+/// # Panics
+/// If the pattern is not a valid regular expression.
+pub fn regex(pattern: &str) -> Pattern {
+    Pattern(Regex::new(pattern).expect("invalid regex pattern"))
+}
+
+impl From<Regex> for Pattern {
+    fn from(regex: Regex) -> Self {
+        Pattern(regex)
+    }
+}
+
+impl Validator for Pattern {
+    fn validate(&self, value: &str) -> Result<(), ValidationError> {
+        if !self.0.is_match(value) {
+            return Err(ValidationError::from_key("pattern", no_params()));
+        }
+        Ok(())
+    }
+}
+
+/// The value must be one of the allowed values, see [`one_of`].
+#[derive(Clone, Debug)]
+pub struct OneOf(Vec<String>);
+
+/// The value must be one of the allowed values.
+pub fn one_of<I, S>(values: I) -> OneOf
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    OneOf(values.into_iter().map(Into::into).collect())
+}
+
+impl Validator for OneOf {
+    fn validate(&self, value: &str) -> Result<(), ValidationError> {
+        if !self.0.iter().any(|v| v == value) {
+            return Err(ValidationError::from_key(
+                "one_of",
+                [("values", self.0.join(", "))],
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// The value must be an email address, see [`email`].
+#[derive(Clone, Debug)]
+pub struct Email;
+
+/// The value must be an email address based on the HTML5 spec.
+pub fn email() -> Email {
+    Email
+}
+
+impl Validator for Email {
+    fn validate(&self, value: &str) -> Result<(), ValidationError> {
+        if !value.validate_email() {
+            return Err(ValidationError::from_key("email", no_params()));
+        }
+        Ok(())
+    }
+}
+
+/// A validator built from a function, see [`custom`].
+#[derive(Clone, Debug)]
+pub struct Custom(fn(&str) -> bool, String);
+
+/// The value must be accepted by the function, or the message is displayed.
+pub fn custom(func: fn(&str) -> bool, message: impl Into<String>) -> Custom {
+    Custom(func, message.into())
+}
+
+impl Validator for Custom {
+    fn validate(&self, value: &str) -> Result<(), ValidationError> {
+        if !(self.0)(value) {
+            return Err(ValidationError::new(self.1.clone()));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(result: Result<(), ValidationError>) -> Option<String> {
+        result.err().and_then(|e| e.key().map(String::from))
+    }
+
+    #[test]
+    fn lengths_count_characters() {
+        assert!(min_len(3).validate("été").is_ok());
+        assert_eq!(
+            key(min_len(3).validate("ét")),
+            Some(String::from("min_len"))
+        );
+        assert!(max_len(3).validate("été").is_ok());
+        assert_eq!(
+            key(max_len(3).validate("étés")),
+            Some(String::from("max_len"))
+        );
+        assert!(min_len(0).validate("").is_ok());
+        assert!(max_len(0).validate("").is_ok());
+    }
+
+    #[test]
+    fn pattern() {
+        let validator = regex(r"^\d{3}$");
+        assert!(validator.validate("123").is_ok());
+        assert_eq!(
+            key(validator.validate("12a")),
+            Some(String::from("pattern"))
+        );
+        let validator = Pattern::from(Regex::new("b").unwrap());
+        assert!(validator.validate("abc").is_ok());
+        assert!(validator.validate("ac").is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid regex pattern")]
+    fn invalid_pattern() {
+        regex("(");
+    }
+
+    #[test]
+    fn one_of_lists_the_values() {
+        let validator = one_of(["red", "green"]);
+        assert!(validator.validate("green").is_ok());
+        let error = validator.validate("Red").unwrap_err();
+        assert_eq!(error.key(), Some("one_of"));
+        assert!(error.message().contains("red, green"));
+    }
+
+    #[test]
+    fn email_follows_html5() {
+        assert!(email().validate("jane@example.com").is_ok());
+        assert_eq!(key(email().validate("jane@")), Some(String::from("email")));
+        assert!(email().validate("jane example.com").is_err());
+    }
+
+    #[test]
+    fn custom_displays_its_message() {
+        let validator = custom(|value| value.starts_with('#'), "Must start with #");
+        assert!(validator.validate("#tag").is_ok());
+        let error = validator.validate("tag").unwrap_err();
+        assert_eq!(error.message(), "Must start with #");
+        assert_eq!(error.key(), None);
+    }
+}
@@ -0,0 +1,283 @@
+use super::patterns::{self, Match};
+use crate::{translate, ValidationError, Validator};
+use std::collections::HashMap;
+
+/// Passwords are analysed in blocks of this length, the longer ones also as a repeat.
+const MAX_ANALYSED_LEN: usize = 64;
+/// The guesses of a part that matches no pattern, per character, as a power of 10.
+const BRUTEFORCE_GUESSES: f64 = 1.0;
+const MIN_GUESSES_SINGLE_CHAR: f64 = 1.0;
+const MIN_GUESSES_MULTI_CHAR: f64 = 1.7;
+/// Penalty of each additional part of a password, as a power of 10.
+const PART_PENALTY: f64 = 4.0;
+
+/// How hard a password is to guess, from very weak to very strong.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PasswordStrength {
+    /// Less than 10^3 guesses, like `password`
+    #[default]
+    VeryWeak,
+    /// Less than 10^6 guesses, like `Passw0rd!`
+    Weak,
+    /// Less than 10^8 guesses
+    Fair,
+    /// Less than 10^10 guesses
+    Strong,
+    /// At least 10^10 guesses
+    VeryStrong,
+}
+
+impl PasswordStrength {
+    /// The strength of the score, from 0 (very weak) to 4 (very strong).
+    pub fn from_score(score: u8) -> Self {
+        match score {
+            0 => PasswordStrength::VeryWeak,
+            1 => PasswordStrength::Weak,
+            2 => PasswordStrength::Fair,
+            3 => PasswordStrength::Strong,
+            _ => PasswordStrength::VeryStrong,
+        }
+    }
+
+    pub fn score(&self) -> u8 {
+        *self as u8
+    }
+
+    /// The name of the strength in the current locale.
+    pub fn message(&self) -> String {
+        let key = match self {
+            PasswordStrength::VeryWeak => "strength.very_weak",
+            PasswordStrength::Weak => "strength.weak",
+            PasswordStrength::Fair => "strength.fair",
+            PasswordStrength::Strong => "strength.strong",
+            PasswordStrength::VeryStrong => "strength.very_strong",
+        };
+        translate(key, &[])
+    }
+
+    fn from_guesses(guesses: f64) -> Self {
+        match guesses {
+            g if g < 3.0 => PasswordStrength::VeryWeak,
+            g if g < 6.0 => PasswordStrength::Weak,
+            g if g < 8.0 => PasswordStrength::Fair,
+            g if g < 10.0 => PasswordStrength::Strong,
+            _ => PasswordStrength::VeryStrong,
+        }
+    }
+}
+
+/// Rejects the passwords weaker than the strength.
+impl Validator for PasswordStrength {
+    fn validate(&self, value: &str) -> Result<(), ValidationError> {
+        if estimate_strength(value).strength < *self {
+            return Err(ValidationError::from_key(
+                "password.too_guessable",
+                crate::validation::no_params(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// The estimated number of guesses needed to find a password.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct StrengthEstimate {
+    /// The number of guesses, as a power of 10
+    pub guesses_log10: f64,
+    pub strength: PasswordStrength,
+}
+
+impl StrengthEstimate {
+    /// The entropy of the password in bits.
+    pub fn entropy(&self) -> f64 {
+        self.guesses_log10 / 2f64.log10()
+    }
+}
+
+/// Estimates how hard a password is to guess.
+///
+/// The password is split into the parts that are the easiest to guess: common words,
+/// including reversed words and l33t substitutions, repeats, sequences, keyboard walks
+/// and dates. The parts matching none of these are brute forced.
+pub fn estimate_strength(password: &str) -> StrengthEstimate {
+    let chars: Vec<char> = password.chars().collect();
+    let mut estimator = Estimator::default();
+    let guesses_log10 = estimator.long_guesses(&chars);
+    StrengthEstimate {
+        guesses_log10,
+        strength: PasswordStrength::from_guesses(guesses_log10),
+    }
+}
+
+/// Estimates the guesses of the parts of a password, keeping the estimates of the repeated
+/// parts, which are estimated again for each of their repeats.
+#[derive(Default)]
+struct Estimator {
+    cache: HashMap<Vec<char>, f64>,
+}
+
+impl Estimator {
+    /// The guesses of the characters analysed in blocks of [`MAX_ANALYSED_LEN`], or as
+    /// a repeat of their shortest unit when they repeat one.
+    fn long_guesses(&mut self, chars: &[char]) -> f64 {
+        if chars.len() <= MAX_ANALYSED_LEN {
+            return self.guesses(chars);
+        }
+        let blocks: f64 = chars
+            .chunks(MAX_ANALYSED_LEN)
+            .map(|block| self.guesses(block))
+            .sum();
+        let unit = patterns::period(chars);
+        if unit > MAX_ANALYSED_LEN {
+            return blocks;
+        }
+        let repeats = (chars.len() as f64 / unit as f64).log10();
+        blocks.min(self.guesses(&chars[..unit]) + repeats)
+    }
+
+    /// The minimal number of guesses of the characters, as a power of 10.
+    fn guesses(&mut self, chars: &[char]) -> f64 {
+        if let Some(guesses) = self.cache.get(chars) {
+            return *guesses;
+        }
+        let guesses = self.estimate(chars);
+        self.cache.insert(chars.to_vec(), guesses);
+        guesses
+    }
+
+    fn estimate(&mut self, chars: &[char]) -> f64 {
+        let n = chars.len();
+        if n == 0 {
+            return 0.0;
+        }
+
+        let mut matches = Vec::new();
+        patterns::dictionary(chars, &mut matches);
+        patterns::repeats(chars, &mut matches, &mut |base| self.guesses(base));
+        patterns::sequences(chars, &mut matches);
+        patterns::keyboard_walks(chars, &mut matches);
+        patterns::dates(chars, &mut matches);
+
+        let mut by_end: Vec<Vec<&Match>> = (0..=n).map(|_| Vec::new()).collect();
+        for m in &matches {
+            by_end[m.end].push(m);
+        }
+        let floor = |len: usize| {
+            if len == 1 {
+                MIN_GUESSES_SINGLE_CHAR
+            } else {
+                MIN_GUESSES_MULTI_CHAR
+            }
+        };
+
+        // best[k][l]: the minimal guesses of the first k characters split in l parts
+        let mut best = vec![vec![f64::INFINITY; n + 1]; n + 1];
+        best[0][0] = 0.0;
+        for k in 1..=n {
+            for l in 1..=k {
+                let mut value = f64::INFINITY;
+                for m in &by_end[k] {
+                    let guesses = m.guesses.max(floor(m.end - m.start));
+                    value = value.min(best[m.start][l - 1] + guesses);
+                }
+                for (start, parts) in best.iter().enumerate().take(k) {
+                    let guesses = (k - start) as f64 * BRUTEFORCE_GUESSES;
+                    value = value.min(parts[l - 1] + guesses.max(floor(k - start)));
+                }
+                best[k][l] = value;
+            }
+        }
+
+        // Guessing the parts in any order, plus a penalty for each additional part.
+        (1..=n)
+            .filter(|l| best[n][*l].is_finite())
+            .map(|l| {
+                let factorial: f64 = (1..=l).map(|i| (i as f64).log10()).sum();
+                add_log10(factorial + best[n][l], (l - 1) as f64 * PART_PENALTY)
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+}
+
+/// `log10(10^a + 10^b)`
+fn add_log10(a: f64, b: f64) -> f64 {
+    let (max, min) = if a > b { (a, b) } else { (b, a) };
+    max + (1.0 + 10f64.powf(min - max)).log10()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strength(password: &str) -> PasswordStrength {
+        estimate_strength(password).strength
+    }
+
+    #[test]
+    fn common_passwords_are_very_weak() {
+        for password in [
+            "password", "P@ssw0rd", "drowssap", "qwerty", "abcdefgh", "98765432",
+        ] {
+            assert_eq!(strength(password), PasswordStrength::VeryWeak, "{password}");
+        }
+    }
+
+    #[test]
+    fn patterns_are_weak() {
+        for password in [
+            "zaq12wsx",
+            "qwertyuiop",
+            "13/05/1987",
+            "aaaaaaaaaaaa",
+            "abcabcabcabc",
+        ] {
+            assert!(strength(password) <= PasswordStrength::Weak, "{password}");
+        }
+    }
+
+    #[test]
+    fn random_passwords_are_very_strong() {
+        for password in ["x7#kQ9!mZ2@p", "correcthorsebatterystaple"] {
+            assert_eq!(
+                strength(password),
+                PasswordStrength::VeryStrong,
+                "{password}"
+            );
+        }
+    }
+
+    #[test]
+    fn empty_password_has_no_guesses() {
+        assert_eq!(estimate_strength("").guesses_log10, 0.0);
+    }
+
+    #[test]
+    fn long_repeats_are_weak() {
+        for len in [64, 65, 100, 1000] {
+            let password = "a".repeat(len);
+            assert!(strength(&password) <= PasswordStrength::Weak, "a * {len}");
+            assert!(PasswordStrength::VeryStrong.validate(&password).is_err());
+        }
+        assert!(strength(&"ab3".repeat(40)) <= PasswordStrength::Weak);
+    }
+
+    #[test]
+    fn characters_past_the_analysed_length_are_analysed() {
+        let random = "kJ8#pQ2$vN5@wR9!mT4^bX7&cZ1*fG6%hL3(dS0)aE8+yU2=iO5?jK9<lP4>nM7~";
+        assert_eq!(random.chars().count(), MAX_ANALYSED_LEN);
+        let base = estimate_strength(random).guesses_log10;
+
+        let walked = estimate_strength(&format!("{random}qwertyuiop")).guesses_log10;
+        assert!(walked - base < 5.0, "{walked} - {base}");
+        let repeated = estimate_strength(&format!("{random}{random}")).guesses_log10;
+        assert!(repeated - base < 1.0, "{repeated} - {base}");
+        let random_tail = estimate_strength(&format!("{random}Zq7!")).guesses_log10;
+        assert!(random_tail > base, "{random_tail} - {base}");
+    }
+
+    #[test]
+    fn validator_rejects_weaker_passwords() {
+        assert!(PasswordStrength::Fair.validate("password").is_err());
+        assert!(PasswordStrength::Fair.validate("x7#kQ9!mZ2@p").is_ok());
+    }
+}
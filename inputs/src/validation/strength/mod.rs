@@ -0,0 +1,4 @@
+mod estimator;
+mod patterns;
+
+pub use estimator::{estimate_strength, PasswordStrength, StrengthEstimate};
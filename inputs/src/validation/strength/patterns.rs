@@ -0,0 +1,457 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// A guessable part of a password, `chars[start..end]`.
+pub(crate) struct Match {
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+    /// The number of guesses needed to find the part, as a power of 10
+    pub(crate) guesses: f64,
+}
+
+impl Match {
+    fn new(start: usize, end: usize, guesses: f64) -> Self {
+        Match {
+            start,
+            end,
+            guesses,
+        }
+    }
+}
+
+/// Words sorted by frequency, the rank of a word is the number of guesses needed to find it.
+const DICTIONARY: &str = include_str!("dictionary.txt");
+const MAX_WORD_LEN: usize = 16;
+/// The year dates are compared to, recent years are guessed first.
+const REFERENCE_YEAR: i32 = 2024;
+const MIN_YEAR_SPACE: i32 = 20;
+
+fn rank(word: &str) -> Option<usize> {
+    static RANKS: OnceLock<HashMap<&'static str, usize>> = OnceLock::new();
+    let ranks = RANKS.get_or_init(|| {
+        let mut ranks = HashMap::new();
+        for (index, word) in DICTIONARY.lines().enumerate() {
+            ranks.entry(word.trim()).or_insert(index + 1);
+        }
+        ranks
+    });
+    ranks.get(word).copied()
+}
+
+/// Common words, reversed words and words with l33t substitutions.
+pub(crate) fn dictionary(chars: &[char], matches: &mut Vec<Match>) {
+    let lower: Vec<char> = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+    for start in 0..chars.len() {
+        for end in start + 3..=chars.len().min(start + MAX_WORD_LEN) {
+            let original = &chars[start..end];
+            let word = &lower[start..end];
+            let uppercase = uppercase_variations(original);
+
+            let candidates = [
+                (word.iter().collect::<String>(), 0.0),
+                (word.iter().rev().collect(), 2f64.log10()),
+                (unleet(word, 'i'), leet_variations(word)),
+                (unleet(word, 'l'), leet_variations(word)),
+            ];
+            let best = candidates
+                .iter()
+                .filter_map(|(word, extra)| rank(word).map(|rank| (rank as f64).log10() + extra))
+                .min_by(f64::total_cmp);
+            if let Some(guesses) = best {
+                matches.push(Match::new(start, end, guesses + uppercase));
+            }
+        }
+    }
+}
+
+fn uppercase_variations(word: &[char]) -> f64 {
+    let upper = word.iter().filter(|c| c.is_uppercase()).count();
+    let lower = word.iter().filter(|c| c.is_lowercase()).count();
+    if upper == 0 {
+        return 0.0;
+    }
+    let first_or_last = word.first().is_some_and(|c| c.is_uppercase())
+        || word.last().is_some_and(|c| c.is_uppercase());
+    if lower == 0 || (upper == 1 && first_or_last) {
+        return 2f64.log10();
+    }
+    let variations: f64 = (1..=upper.min(lower))
+        .map(|i| binomial(upper + lower, i))
+        .sum();
+    variations.log10()
+}
+
+fn leet(c: char) -> Option<char> {
+    match c {
+        '4' | '@' => Some('a'),
+        '8' => Some('b'),
+        '(' => Some('c'),
+        '3' => Some('e'),
+        '6' | '9' => Some('g'),
+        '1' | '!' | '|' => Some('i'),
+        '0' => Some('o'),
+        '$' | '5' => Some('s'),
+        '7' | '+' => Some('t'),
+        '2' => Some('z'),
+        _ => None,
+    }
+}
+
+/// Replaces the l33t substitutions, `1` and `|` standing for `one`.
+fn unleet(word: &[char], one: char) -> String {
+    word.iter()
+        .map(|c| match (c, leet(*c)) {
+            ('1' | '|', _) => one,
+            (_, Some(letter)) => letter,
+            (c, None) => *c,
+        })
+        .collect()
+}
+
+fn leet_variations(word: &[char]) -> f64 {
+    let substitutions = word.iter().filter(|c| leet(**c).is_some()).count();
+    if substitutions == 0 {
+        return f64::INFINITY;
+    }
+    substitutions as f64 * 2f64.log10()
+}
+
+/// Characters or groups of characters repeated, like `aaa` or `abcabc`.
+///
+/// Only the shortest unit of a repeat is estimated, `abab` repeated twice being `ab`
+/// repeated four times.
+pub(crate) fn repeats(
+    chars: &[char],
+    matches: &mut Vec<Match>,
+    estimate: &mut dyn FnMut(&[char]) -> f64,
+) {
+    for start in 0..chars.len() {
+        for len in 1..=(chars.len() - start) / 2 {
+            let base = &chars[start..start + len];
+            let count = chars[start..]
+                .chunks_exact(len)
+                .take_while(|chunk| *chunk == base)
+                .count();
+            let unit = period(base);
+            if count < 2 || count * len < 3 || (unit < len && len % unit == 0) {
+                continue;
+            }
+            let guesses = estimate(base) + (count as f64).log10();
+            matches.push(Match::new(start, start + count * len, guesses));
+        }
+    }
+}
+
+/// The length of the shortest unit the characters repeat, like 2 for `abababa`, or their
+/// length when they repeat none.
+pub(crate) fn period(chars: &[char]) -> usize {
+    (1..chars.len())
+        .find(|len| chars[*len..].iter().zip(chars).all(|(a, b)| a == b))
+        .unwrap_or(chars.len())
+}
+
+/// Letters or digits in order, like `abcd`, `9876` or `XYZ`.
+pub(crate) fn sequences(chars: &[char], matches: &mut Vec<Match>) {
+    let class = |c: char| {
+        if c.is_ascii_lowercase() {
+            Some(26.0)
+        } else if c.is_ascii_uppercase() {
+            Some(52.0)
+        } else if c.is_ascii_digit() {
+            Some(10.0)
+        } else {
+            None
+        }
+    };
+    for start in 0..chars.len().saturating_sub(2) {
+        let Some(cardinality) = class(chars[start]) else {
+            continue;
+        };
+        let delta = chars[start + 1] as i32 - chars[start] as i32;
+        if delta.abs() != 1 {
+            continue;
+        }
+        let mut end = start + 1;
+        while end < chars.len()
+            && chars[end] as i32 - chars[end - 1] as i32 == delta
+            && class(chars[end]) == Some(cardinality)
+        {
+            end += 1;
+        }
+        if end - start < 3 {
+            continue;
+        }
+        let base = if "aAzZ019".contains(chars[start]) {
+            4.0
+        } else {
+            cardinality
+        };
+        let direction = if delta > 0 { 1.0 } else { 2.0 };
+        let guesses = base * (end - start) as f64 * direction;
+        matches.push(Match::new(start, end, guesses.log10()));
+    }
+}
+
+const KEYBOARD: [(&str, &str, f64); 4] = [
+    ("`1234567890-=", "~!@#$%^&*()_+", 0.0),
+    ("qwertyuiop[]\\", "QWERTYUIOP{}|", 1.5),
+    ("asdfghjkl;'", "ASDFGHJKL:\"", 1.75),
+    ("zxcvbnm,./", "ZXCVBNM<>?", 2.25),
+];
+const KEYBOARD_KEYS: f64 = 47.0;
+const KEYBOARD_DEGREE: f64 = 4.6;
+
+/// The row and horizontal position of a key on a qwerty keyboard, and whether it is shifted.
+fn key(c: char) -> Option<(i32, f64, bool)> {
+    KEYBOARD
+        .iter()
+        .enumerate()
+        .find_map(|(row, (keys, shifted, offset))| {
+            let position = |keys: &str| keys.chars().position(|k| k == c);
+            position(keys)
+                .map(|col| (row as i32, col as f64 + offset, false))
+                .or_else(|| position(shifted).map(|col| (row as i32, col as f64 + offset, true)))
+        })
+}
+
+/// Neighbouring keys typed in a row, like `qwerty` or `zaq1`.
+pub(crate) fn keyboard_walks(chars: &[char], matches: &mut Vec<Match>) {
+    let direction = |from: (i32, f64, bool), to: (i32, f64, bool)| {
+        let (rows, columns) = (to.0 - from.0, to.1 - from.1);
+        let adjacent = match rows.abs() {
+            0 => columns.abs() == 1.0,
+            1 => columns.abs() < 1.0,
+            _ => false,
+        };
+        adjacent.then_some((rows, columns > 0.0))
+    };
+
+    let mut start = 0;
+    while start + 2 < chars.len() {
+        let mut end = start + 1;
+        let mut turns = 1;
+        let mut shifted = usize::from(key(chars[start]).is_some_and(|k| k.2));
+        let mut last_direction = None;
+        while end < chars.len() {
+            let (Some(from), Some(to)) = (key(chars[end - 1]), key(chars[end])) else {
+                break;
+            };
+            let Some(current) = direction(from, to) else {
+                break;
+            };
+            if last_direction.is_some_and(|last| last != current) {
+                turns += 1;
+            }
+            last_direction = Some(current);
+            shifted += usize::from(to.2);
+            end += 1;
+        }
+        if end - start >= 3 {
+            let len = end - start;
+            let mut guesses = 0.0;
+            for l in 2..=len {
+                for t in 1..=turns.min(l - 1) {
+                    guesses +=
+                        binomial(l - 1, t - 1) * KEYBOARD_KEYS * KEYBOARD_DEGREE.powi(t as i32);
+                }
+            }
+            if shifted > 0 && shifted < len {
+                guesses *= (1..=shifted.min(len - shifted))
+                    .map(|i| binomial(len, i))
+                    .sum::<f64>();
+            } else if shifted == len {
+                guesses *= 2.0;
+            }
+            matches.push(Match::new(start, end, guesses.log10()));
+            start = end - 1;
+        } else {
+            start += 1;
+        }
+    }
+}
+
+/// Years and dates, with or without separators, like `1987`, `13/05/87` or `19870513`.
+pub(crate) fn dates(chars: &[char], matches: &mut Vec<Match>) {
+    for start in 0..chars.len() {
+        for end in start + 4..=chars.len().min(start + 10) {
+            let text: String = chars[start..end].iter().collect();
+            if let Some(guesses) = date_guesses(&text) {
+                matches.push(Match::new(start, end, guesses.log10()));
+            }
+        }
+    }
+}
+
+fn date_guesses(text: &str) -> Option<f64> {
+    let year_space = |year: i32| ((year - REFERENCE_YEAR).abs().max(MIN_YEAR_SPACE)) as f64;
+
+    if text.chars().all(|c| c.is_ascii_digit()) {
+        if text.len() == 4 {
+            let year = full_year(text)?;
+            return Some(year_space(year));
+        }
+        let splits: &[(usize, usize)] = match text.len() {
+            6 => &[(2, 4), (4, 6)],
+            8 => &[(2, 4), (4, 6)],
+            _ => return None,
+        };
+        return splits
+            .iter()
+            .filter_map(|(a, b)| {
+                let parts = [&text[..*a], &text[*a..*b], &text[*b..]];
+                date_year(&parts)
+            })
+            .map(|year| 365.0 * year_space(year))
+            .reduce(f64::min);
+    }
+
+    let separator = text.chars().find(|c| !c.is_ascii_digit())?;
+    if !"/-._ ".contains(separator) {
+        return None;
+    }
+    let parts: Vec<&str> = text.split(separator).collect();
+    if parts.len() != 3
+        || parts
+            .iter()
+            .any(|p| p.is_empty() || !p.chars().all(|c| c.is_ascii_digit()))
+    {
+        return None;
+    }
+    let year = date_year(&[parts[0], parts[1], parts[2]])?;
+    Some(365.0 * year_space(year) * 4.0)
+}
+
+/// The year of the date if the parts are a valid day, month and year in any common order.
+fn date_year(parts: &[&str; 3]) -> Option<i32> {
+    let number = |part: &str| part.parse::<u32>().ok();
+    let valid = |day: &str, month: &str| {
+        day.len() <= 2
+            && month.len() <= 2
+            && number(day).is_some_and(|d| (1..=31).contains(&d))
+            && number(month).is_some_and(|m| (1..=12).contains(&m))
+    };
+    let [a, b, c] = *parts;
+    if valid(a, b) || valid(b, a) {
+        if let Some(year) = full_year(c) {
+            return Some(year);
+        }
+    }
+    if valid(c, b) {
+        return full_year(a);
+    }
+    None
+}
+
+fn full_year(year: &str) -> Option<i32> {
+    let value = year.parse::<i32>().ok()?;
+    match year.len() {
+        2 if value > 50 => Some(1900 + value),
+        2 => Some(2000 + value),
+        4 if (1900..=2050).contains(&value) => Some(value),
+        _ => None,
+    }
+}
+
+fn binomial(n: usize, k: usize) -> f64 {
+    (1..=k).fold(1.0, |result, i| result * (n + 1 - i) as f64 / i as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(text: &str) -> Vec<char> {
+        text.chars().collect()
+    }
+
+    /// The guesses of the match covering the whole text, if any.
+    fn whole(text: &str, find: impl Fn(&[char], &mut Vec<Match>)) -> Option<f64> {
+        let chars = chars(text);
+        let mut matches = Vec::new();
+        find(&chars, &mut matches);
+        matches
+            .iter()
+            .filter(|m| m.start == 0 && m.end == chars.len())
+            .map(|m| m.guesses)
+            .min_by(f64::total_cmp)
+    }
+
+    #[test]
+    fn dictionary_ranks_words() {
+        assert_eq!(whole("password", dictionary), Some(0.0));
+        assert_eq!(whole("qwerty", dictionary), Some(2f64.log10()));
+        assert_eq!(whole("xkqzvw", dictionary), None);
+    }
+
+    #[test]
+    fn dictionary_finds_variations() {
+        let plain = whole("password", dictionary).unwrap();
+        for variation in ["drowssap", "Password", "p@ssw0rd", "P4$$W0RD"] {
+            let guesses = whole(variation, dictionary).unwrap();
+            assert!(guesses > plain, "{variation}");
+        }
+    }
+
+    #[test]
+    fn sequences_in_both_directions() {
+        assert!(whole("abcdef", sequences).is_some());
+        assert!(whole("98765", sequences).is_some());
+        assert!(whole("XYZ", sequences).is_some());
+        assert!(whole("abd", sequences).is_none());
+        assert!(whole("ab", sequences).is_none());
+        assert!(whole("abcdef", sequences) < whole("fedcba", sequences));
+    }
+
+    #[test]
+    fn keyboard_walks_with_turns() {
+        let straight = whole("qwerty", keyboard_walks).unwrap();
+        let turning = whole("zaq12wsx", keyboard_walks).unwrap();
+        assert!(straight < turning);
+        assert!(whole("QWERTY", keyboard_walks).unwrap() > straight);
+        assert!(whole("qwe", keyboard_walks).is_some());
+        assert!(whole("qpz", keyboard_walks).is_none());
+    }
+
+    #[test]
+    fn dates_and_years() {
+        for date in ["1987", "13/05/1987", "1987-05-13", "130587", "19870513"] {
+            assert!(whole(date, dates).is_some(), "{date}");
+        }
+        for not_date in ["1850", "32/13/1987", "12345"] {
+            assert!(whole(not_date, dates).is_none(), "{not_date}");
+        }
+    }
+
+    #[test]
+    fn repeats_estimate_the_shortest_unit() {
+        let mut estimated = Vec::new();
+        let mut matches = Vec::new();
+        repeats(&chars("abababab"), &mut matches, &mut |base| {
+            estimated.push(base.iter().collect::<String>());
+            1.0
+        });
+        estimated.sort();
+        estimated.dedup();
+        assert_eq!(estimated, ["ab", "ba"]);
+        let whole = matches.iter().find(|m| m.start == 0 && m.end == 8).unwrap();
+        assert_eq!(whole.guesses, 1.0 + 4f64.log10());
+    }
+
+    #[test]
+    fn repeats_of_a_periodic_unit() {
+        let mut matches = Vec::new();
+        repeats(&chars("abaaba"), &mut matches, &mut |_| 1.0);
+        assert!(matches.iter().any(|m| m.start == 0 && m.end == 6));
+    }
+
+    #[test]
+    fn period_of_repeats() {
+        assert_eq!(period(&chars("aaaa")), 1);
+        assert_eq!(period(&chars("abababa")), 2);
+        assert_eq!(period(&chars("abc")), 3);
+        assert_eq!(period(&[]), 0);
+    }
+}
@@ -0,0 +1,360 @@
+use crate::i18n::translate;
+use crate::validation::{CrossField, PasswordPolicy, PasswordStrength};
+use crate::validation::{Custom, Email, EmailPolicy, Matches, MaxLen, MinLen, OneOf, Pattern};
+use crate::PasswordValidationLevel;
+use std::fmt::{Debug, Display, Formatter};
+use std::rc::Rc;
+
+/// The error returned by a [`Validator`] when a value is rejected.
+///
+/// Errors created from a key are translated with the [`I18n`](crate::I18n) context
+/// when they are displayed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    message: String,
+    key: Option<String>,
+    params: Vec<(String, String)>,
+}
+
+impl ValidationError {
+    /// An error displaying the message as is.
+    pub fn new(message: impl Into<String>) -> Self {
+        ValidationError {
+            message: message.into(),
+            key: None,
+            params: Vec::new(),
+        }
+    }
+
+    /// An error displaying the message of the key in the current locale,
+    /// with its parameters replaced.
+    pub fn from_key<I, K, V>(key: impl Into<String>, params: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: ToString,
+    {
+        let key = key.into();
+        let params: Vec<(String, String)> = params
+            .into_iter()
+            .map(|(k, v)| (k.into(), v.to_string()))
+            .collect();
+        ValidationError {
+            message: translate(&key, &borrow_params(&params)),
+            key: Some(key),
+            params,
+        }
+    }
+
+    /// The message of the error, in the locale that was active when it was created.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The key of the message in the catalogs, if any.
+    pub fn key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+
+    /// The message of the error in the current locale.
+    pub fn localized(&self) -> String {
+        match &self.key {
+            Some(key) => translate(key, &borrow_params(&self.params)),
+            None => self.message.clone(),
+        }
+    }
+}
+
+/// The parameters of an error without parameters, see [`ValidationError::from_key`].
+pub(crate) fn no_params() -> [(&'static str, &'static str); 0] {
+    []
+}
+
+fn borrow_params(params: &[(String, String)]) -> Vec<(&str, String)> {
+    params
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.clone()))
+        .collect()
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// A validation rule that can be shared by every input of the crate.
+///
+/// Rules are combined with [`Validator::and`] and [`Validator::or`], and can be
+/// given a custom message with [`Validator::with_message`].
+/// Any closure `Fn(&str) -> Result<(), ValidationError>` is a validator.
+pub trait Validator {
+    /// Validates the value, returning the reason of the failure if it is rejected.
+    fn validate(&self, value: &str) -> Result<(), ValidationError>;
+
+    /// The value must be accepted by both validators. The first failure is returned.
+    fn and<V: Validator>(self, other: V) -> And<Self, V>
+    where
+        Self: Sized,
+    {
+        And(self, other)
+    }
+
+    /// The value must be accepted by at least one validator.
+    /// If both reject it, the error of the second one is returned.
+    fn or<V: Validator>(self, other: V) -> Or<Self, V>
+    where
+        Self: Sized,
+    {
+        Or(self, other)
+    }
+
+    /// Replaces the message of the error returned by the validator.
+    fn with_message(self, message: impl Into<String>) -> WithMessage<Self>
+    where
+        Self: Sized,
+    {
+        WithMessage(self, ValidationError::new(message))
+    }
+
+    /// Replaces the message of the error returned by the validator with the
+    /// message of the key in the current locale.
+    fn with_message_key(self, key: impl Into<String>) -> WithMessage<Self>
+    where
+        Self: Sized,
+    {
+        WithMessage(self, ValidationError::from_key(key, no_params()))
+    }
+}
+
+impl<F> Validator for F
+where
+    F: Fn(&str) -> Result<(), ValidationError>,
+{
+    fn validate(&self, value: &str) -> Result<(), ValidationError> {
+        self(value)
+    }
+}
+
+/// See [`Validator::and`].
+#[derive(Clone, Debug)]
+pub struct And<A, B>(A, B);
+
+impl<A: Validator, B: Validator> Validator for And<A, B> {
+    fn validate(&self, value: &str) -> Result<(), ValidationError> {
+        self.0.validate(value)?;
+        self.1.validate(value)
+    }
+}
+
+/// See [`Validator::or`].
+#[derive(Clone, Debug)]
+pub struct Or<A, B>(A, B);
+
+impl<A: Validator, B: Validator> Validator for Or<A, B> {
+    fn validate(&self, value: &str) -> Result<(), ValidationError> {
+        self.0.validate(value).or_else(|_| self.1.validate(value))
+    }
+}
+
+/// See [`Validator::with_message`] and [`Validator::with_message_key`].
+#[derive(Clone, Debug)]
+pub struct WithMessage<V>(V, ValidationError);
+
+impl<V: Validator> Validator for WithMessage<V> {
+    fn validate(&self, value: &str) -> Result<(), ValidationError> {
+        self.0.validate(value).map_err(|_| self.1.clone())
+    }
+}
+
+/// A type erased [`Validator`], as accepted by the inputs.
+///
+/// It is cheap to clone, so a rule can be written once and given to many inputs.
+/// The closures and the validators of the crate convert into it, other validators are
+/// erased with [`AnyValidator::new`].
+#[derive(Clone)]
+pub struct AnyValidator(Rc<dyn Validator>);
+
+impl AnyValidator {
+    pub fn new(validator: impl Validator + 'static) -> Self {
+        AnyValidator(Rc::new(validator))
+    }
+
+    pub fn validate(&self, value: &str) -> Result<(), ValidationError> {
+        self.0.validate(value)
+    }
+
+    /// Combines two validators, see [`Validator::and`].
+    pub fn and(self, other: impl Into<AnyValidator>) -> AnyValidator {
+        let other = other.into();
+        AnyValidator::new(move |value: &str| {
+            self.validate(value)?;
+            other.validate(value)
+        })
+    }
+
+    /// Combines two validators, see [`Validator::or`].
+    pub fn or(self, other: impl Into<AnyValidator>) -> AnyValidator {
+        let other = other.into();
+        AnyValidator::new(move |value: &str| {
+            self.validate(value).or_else(|_| other.validate(value))
+        })
+    }
+}
+
+impl Validator for AnyValidator {
+    fn validate(&self, value: &str) -> Result<(), ValidationError> {
+        self.0.validate(value)
+    }
+}
+
+impl<F> From<F> for AnyValidator
+where
+    F: Fn(&str) -> Result<(), ValidationError> + 'static,
+{
+    fn from(validator: F) -> Self {
+        AnyValidator::new(validator)
+    }
+}
+
+/// Converts the validators of the crate into an [`AnyValidator`].
+macro_rules! into_any_validator {
+    ($($validator:ty),* $(,)?) => {
+        $(
+            impl From<$validator> for AnyValidator {
+                fn from(validator: $validator) -> Self {
+                    AnyValidator::new(validator)
+                }
+            }
+        )*
+    };
+}
+
+into_any_validator!(
+    MinLen,
+    MaxLen,
+    Pattern,
+    OneOf,
+    Email,
+    Custom,
+    Matches,
+    EmailPolicy,
+    PasswordPolicy,
+    PasswordStrength,
+    PasswordValidationLevel,
+);
+
+impl<A: Validator + 'static, B: Validator + 'static> From<And<A, B>> for AnyValidator {
+    fn from(validator: And<A, B>) -> Self {
+        AnyValidator::new(validator)
+    }
+}
+
+impl<A: Validator + 'static, B: Validator + 'static> From<Or<A, B>> for AnyValidator {
+    fn from(validator: Or<A, B>) -> Self {
+        AnyValidator::new(validator)
+    }
+}
+
+impl<V: Validator + 'static> From<WithMessage<V>> for AnyValidator {
+    fn from(validator: WithMessage<V>) -> Self {
+        AnyValidator::new(validator)
+    }
+}
+
+impl<T, F> From<CrossField<T, F>> for AnyValidator
+where
+    F: Fn(&str, &T) -> bool + 'static,
+{
+    fn from(validator: CrossField<T, F>) -> Self {
+        AnyValidator::new(validator)
+    }
+}
+
+impl Debug for AnyValidator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AnyValidator").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{max_len, min_len};
+
+    fn key(result: Result<(), ValidationError>) -> Option<String> {
+        result.err().and_then(|e| e.key().map(String::from))
+    }
+
+    #[test]
+    fn and_returns_the_first_failure() {
+        let validator = min_len(2).and(max_len(4));
+        assert!(validator.validate("abc").is_ok());
+        assert_eq!(key(validator.validate("a")), Some(String::from("min_len")));
+        assert_eq!(
+            key(validator.validate("abcde")),
+            Some(String::from("max_len"))
+        );
+        let never = min_len(3).and(max_len(1));
+        assert_eq!(key(never.validate("ab")), Some(String::from("min_len")));
+    }
+
+    #[test]
+    fn or_returns_the_second_failure() {
+        let validator = max_len(1).or(min_len(4));
+        assert!(validator.validate("a").is_ok());
+        assert!(validator.validate("abcd").is_ok());
+        assert_eq!(
+            key(validator.validate("abc")),
+            Some(String::from("min_len"))
+        );
+    }
+
+    #[test]
+    fn with_message_replaces_the_error() {
+        let validator = min_len(3).with_message("Too short");
+        assert!(validator.validate("abc").is_ok());
+        let error = validator.validate("ab").unwrap_err();
+        assert_eq!(error.message(), "Too short");
+        assert_eq!(error.key(), None);
+        let validator = min_len(3).with_message_key("required");
+        assert_eq!(
+            key(validator.validate("ab")),
+            Some(String::from("required"))
+        );
+    }
+
+    #[test]
+    fn closures_are_validators() {
+        let no_spaces = |value: &str| {
+            if value.contains(' ') {
+                return Err(ValidationError::new("No spaces"));
+            }
+            Ok(())
+        };
+        assert!(no_spaces.validate("ab").is_ok());
+        let validator = AnyValidator::from(no_spaces);
+        assert_eq!(
+            validator.validate("a b").unwrap_err().message(),
+            "No spaces"
+        );
+    }
+
+    #[test]
+    fn erased_validators_combine() {
+        let erased = AnyValidator::new(min_len(2));
+        let validator = max_len(4).and(erased.clone());
+        assert_eq!(key(validator.validate("a")), Some(String::from("min_len")));
+        let validator = erased.clone().with_message("Too short");
+        assert_eq!(validator.validate("a").unwrap_err().message(), "Too short");
+        let validator = erased.and(max_len(3)).or(min_len(10));
+        assert!(validator.validate("ab").is_ok());
+        assert!(validator.validate("abcdefghij").is_ok());
+        assert_eq!(
+            key(validator.validate("abcd")),
+            Some(String::from("min_len"))
+        );
+    }
+}
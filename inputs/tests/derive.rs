@@ -0,0 +1,35 @@
+#![cfg(feature = "derive")]
+
+use leptos_inputs::validator::Validate;
+use leptos_inputs::Form;
+
+#[derive(Clone, Debug, Validate, Form)]
+#[validate(crate = "leptos_inputs::validator")]
+#[form(title = "Sign up")]
+pub struct Signup {
+    #[validate(length(min = 3, max = 20))]
+    username: String,
+    #[validate(email)]
+    email: String,
+    #[validate(length(min = 8))]
+    password: String,
+    nickname: Option<String>,
+}
+
+#[test]
+fn derives_through_the_reexported_validator() {
+    let signup = Signup {
+        username: String::from("ab"),
+        email: String::from("jane@example.com"),
+        password: String::from("correct horse"),
+        nickname: None,
+    };
+    assert_eq!(signup.nickname, None);
+    let errors = signup.validate().unwrap_err();
+    let fields = errors.field_errors();
+    assert!(fields.contains_key("username"));
+    assert_eq!(fields.len(), 1);
+
+    // The form component is generated next to the struct.
+    let _ = SignupForm;
+}